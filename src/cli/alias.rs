@@ -0,0 +1,117 @@
+use rootcause::prelude::*;
+use std::collections::HashMap;
+
+/// Names that can never be shadowed by a user-defined alias, since they're handled directly by clap.
+const RESERVED_NAMES: &[&str] = &["help", "version", "-h", "--help", "-V", "--version"];
+
+/// Maximum number of alias hops we'll follow before assuming a cycle.
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Expands a user-defined alias found as the first non-flag argument in `args` (which should not include the
+/// binary name), splicing the alias's whitespace-split tokens in its place.
+///
+/// An alias may itself expand to another alias, but expansion is bounded by `MAX_ALIAS_DEPTH` and bails if the
+/// same alias is seen twice, to guard against self-referential/recursive definitions. Reserved names
+/// (`help`, `version`, ...) are never looked up, so an alias can't shadow them.
+pub(crate) fn expand_aliases(
+    args: Vec<String>,
+    aliases: &HashMap<String, String>,
+) -> Result<Vec<String>, Report> {
+    if aliases.is_empty() {
+        return Ok(args);
+    }
+
+    let Some(first_idx) = args.iter().position(|arg| !arg.starts_with('-')) else {
+        return Ok(args);
+    };
+
+    let mut result = args;
+    let mut seen: Vec<String> = Vec::new();
+
+    loop {
+        let candidate = result[first_idx].clone();
+
+        if RESERVED_NAMES.contains(&candidate.as_str()) {
+            break;
+        }
+
+        let Some(expansion) = aliases.get(&candidate) else {
+            break;
+        };
+
+        if seen.contains(&candidate) {
+            bail!(
+                "Alias `{candidate}` is defined recursively; check the `[aliases]` table in your configuration"
+            );
+        }
+
+        if seen.len() >= MAX_ALIAS_DEPTH {
+            bail!(
+                "Exceeded maximum alias expansion depth ({MAX_ALIAS_DEPTH}) resolving `{candidate}`; check for a \
+                cycle in the `[aliases]` table"
+            );
+        }
+
+        seen.push(candidate);
+
+        let tokens: Vec<String> = expansion.split_whitespace().map(str::to_string).collect();
+
+        result.splice(first_idx..=first_idx, tokens);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expands_a_simple_alias() {
+        let args = vec!["rel".to_string(), "1.2.3".to_string()];
+        let aliases = aliases(&[("rel", "--use-llm --output-format json")]);
+
+        let expanded = expand_aliases(args, &aliases).unwrap();
+
+        assert_eq!(
+            expanded,
+            vec!["--use-llm", "--output-format", "json", "1.2.3"]
+        );
+    }
+
+    #[test]
+    fn leaves_args_untouched_when_no_alias_matches() {
+        let args = vec!["1.2.3".to_string(), "--debug".to_string()];
+        let aliases = aliases(&[("rel", "--use-llm")]);
+
+        let expanded = expand_aliases(args.clone(), &aliases).unwrap();
+
+        assert_eq!(expanded, args);
+    }
+
+    #[test]
+    fn does_not_shadow_reserved_names() {
+        let args = vec!["help".to_string()];
+        let aliases = aliases(&[("help", "--use-llm")]);
+
+        let expanded = expand_aliases(args.clone(), &aliases).unwrap();
+
+        assert_eq!(expanded, args);
+    }
+
+    #[test]
+    fn errors_on_recursive_alias() {
+        let args = vec!["rel".to_string()];
+        let aliases = aliases(&[("rel", "rel")]);
+
+        let err = expand_aliases(args, &aliases).unwrap_err();
+        assert!(format!("{err:#}").contains("defined recursively"));
+    }
+}
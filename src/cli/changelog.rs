@@ -53,12 +53,22 @@ fn open_file_in_editor(file_path: &str) -> Result<(), Report> {
         .split_first()
         .ok_or_else(|| std::io::Error::other("Editor path is empty or invalid"))?;
 
-    std::process::Command::new(cmd)
+    let status = std::process::Command::new(cmd)
         .args(args)
         .stdin(std::process::Stdio::inherit())
         .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
-        .status()?;
+        .status()
+        .map_err(|e| report!("Could not launch editor `{cmd}`: {e}"))?;
+
+    if !status.success() {
+        let how = match status.code() {
+            Some(code) => format!("exit code {code}"),
+            None => "no exit code (likely terminated by a signal)".to_string(),
+        };
+
+        bail!("Editor `{cmd}` exited with {how}; changelog edit aborted");
+    }
 
     Ok(())
 }
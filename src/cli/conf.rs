@@ -4,7 +4,7 @@ use figment::{
 };
 use rootcause::prelude::*;
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 pub trait ConfigType: Deserialize<'static> {
     fn default_config() -> &'static str;
@@ -23,8 +23,37 @@ impl<T: ConfigType> Configuration<T> {
         if let Some(path) = path_override {
             config = config.merge(Toml::file(path));
         } else {
-            for path in T::config_paths() {
-                config = config.merge(Toml::file(path));
+            let existing_paths: Vec<PathBuf> = T::config_paths()
+                .into_iter()
+                .filter(|path| path.exists())
+                .collect();
+
+            if existing_paths.len() > 1 {
+                let primary_env = format!("{}CONFIG_PRIMARY", T::env_prefix());
+                let primary = std::env::var(&primary_env)
+                    .ok()
+                    .map(PathBuf::from)
+                    .filter(|path| existing_paths.contains(path));
+
+                match primary {
+                    Some(primary_path) => config = config.merge(Toml::file(primary_path)),
+                    None => {
+                        let found = existing_paths
+                            .iter()
+                            .map(|path| path.display().to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        bail!(
+                            "Found multiple configuration files ({found}); consolidate them into a single file, or \
+                            set `{primary_env}` to the one that should take precedence"
+                        );
+                    }
+                }
+            } else {
+                for path in existing_paths {
+                    config = config.merge(Toml::file(path));
+                }
             }
         }
 
@@ -39,10 +68,24 @@ impl<T: ConfigType> Configuration<T> {
         //
         // To solve this we use a double underscore which denotes the difference between what are actual
         // keys and what are levels of the struct we need to dive into.
-        config = config.merge(Env::prefixed(T::env_prefix()).split("__"));
-        let parsed_config: T = config.extract()?;
+        //
+        // `config_primary` (the `{prefix}CONFIG_PRIMARY` variable read above) is excluded here: it's a meta knob
+        // for resolving config-file ambiguity, not an actual `T` field, so leaving it in would make
+        // `deny_unknown_fields` below reject it as an unrecognized key.
+        config = config.merge(Env::prefixed(T::env_prefix()).ignore(&["config_primary"]).split("__"));
 
-        Ok(parsed_config)
+        config.extract().map_err(|e| {
+            if let figment::error::Kind::UnknownField(name, candidates) = &e.kind
+                && let Some(hint) = crate::cli::suggest::did_you_mean(
+                    name,
+                    candidates.iter().copied(),
+                )
+            {
+                return report!("{e} ({hint})");
+            }
+
+            report!("{e}")
+        })
     }
 }
 
@@ -50,6 +93,7 @@ impl<T: ConfigType> Configuration<T> {
 const DEFAULT_CLI_CONFIG: &str = include_str!("./default_cli_config.toml");
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
 pub struct CliConfig {
     /// Provides extra debug output.
     pub debug: bool,
@@ -58,14 +102,116 @@ pub struct CliConfig {
     #[serde(deserialize_with = "crate::cli::deserialize_output_format")]
     pub output_format: crate::cli::OutputFormat,
 
+    /// Whether to emit ANSI colors (auto/always/never).
+    #[serde(deserialize_with = "crate::cli::deserialize_color_choice")]
+    pub color: crate::cli::ColorChoice,
+
     /// Whether to use LLMs to help create changelog notes.
     pub llm: Llm,
 
     /// Github specific configurations.
     pub github: Github,
+
+    /// User-defined command shorthands, e.g. `rel = "--use-llm --output-format json"`. The first non-flag argument
+    /// on the command line is looked up here before clap parses anything.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+
+    /// Per-remote forge API configuration, keyed by the remote's host (e.g. `"git.mycompany.com"`). Lets a
+    /// self-hosted Gitea/Forgejo/GitLab instance whose hostname doesn't give away what it's running be targeted
+    /// correctly; github.com needs no entry here unless you want to override how its token is resolved.
+    #[serde(default)]
+    pub forges: HashMap<String, crate::cli::forge::ForgeEndpoint>,
+
+    /// Changelog rendering options.
+    #[serde(default)]
+    pub changelog: Changelog,
+
+    /// Post-release publishing to package-manager registries (cargo today; more ecosystems can follow).
+    #[serde(default)]
+    pub publish: Publish,
+
+    /// Release details (the pre-confirmation summary) rendering options.
+    #[serde(default)]
+    pub release_details: ReleaseDetails,
+
+    /// Restricts and orders which tags count as "the latest" when scanning for the base of a release, e.g. for a
+    /// monorepo that tags each sub-project separately.
+    #[serde(default)]
+    pub tags: Tags,
+
+    /// Restricts which commits count towards a release by the paths they touched, e.g. for a monorepo that cuts
+    /// a release for just one sub-project.
+    #[serde(default)]
+    pub paths: Paths,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Tags {
+    /// Restricts candidate tags to those whose shorthand matches this glob (e.g. `"api-v*"` for a monorepo).
+    #[serde(default)]
+    pub pattern: Option<String>,
+
+    /// A literal prefix to strip before SemVer parsing, on top of the `v` we always strip (e.g. `"api-v"` turns
+    /// `api-v1.2.3` into `1.2.3`). Tags missing the prefix are skipped.
+    #[serde(default)]
+    pub strip_prefix: Option<String>,
+
+    /// Excludes versions with a non-empty SemVer prerelease identifier (e.g. `2.0.0-rc.1`) from consideration as
+    /// "latest".
+    #[serde(default)]
+    pub skip_prereleases: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Paths {
+    /// Only commits touching a path matching at least one of these globs count, e.g. `["crates/foo/**"]`. Empty
+    /// means every path counts.
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Commits touching only paths matching one of these globs are dropped, even if they'd otherwise match
+    /// `include` (e.g. excluding `"crates/foo/README.md"` while including `"crates/foo/**"`).
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Changelog {
+    /// Overrides the heading text for a commit category (`features`, `fixes`, `performance`, `breaking`, `other`).
+    /// Set a category to an empty string to hide it from the rendered changelog entirely.
+    #[serde(default)]
+    pub headings: HashMap<String, String>,
+
+    /// Path to a custom Tera template overriding the built-in changelog template. Checked at config-resolution
+    /// time so a broken template fails fast rather than mid-release; `template` takes precedence if both are set.
+    #[serde(default)]
+    pub template_path: Option<PathBuf>,
+
+    /// An inline Tera template, as an alternative to `template_path`.
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ReleaseDetails {
+    /// Path to a custom Tera template overriding the built-in release-details template. Checked at
+    /// config-resolution time so a broken template fails fast rather than mid-release; `template` takes precedence
+    /// if both are set.
+    #[serde(default)]
+    pub template_path: Option<PathBuf>,
+
+    /// An inline Tera template, as an alternative to `template_path`.
+    #[serde(default)]
+    pub template: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
 pub struct Llm {
     pub enable: bool,
 
@@ -80,10 +226,33 @@ pub struct Llm {
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
 pub struct Github {
     pub token: String,
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Publish {
+    /// Turns on the post-release publish phase. Off by default, since publishing to a registry is typically a more
+    /// consequential, harder-to-undo action than just cutting a GitHub release.
+    #[serde(default)]
+    pub enable: bool,
+
+    /// Cargo/crates.io specific publish options.
+    #[serde(default)]
+    pub cargo: CargoPublish,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CargoPublish {
+    /// Registries to `cargo publish` to, by name as configured in `~/.cargo/config.toml`. Empty means just the
+    /// default crates.io registry.
+    #[serde(default)]
+    pub registries: Vec<String>,
+}
+
 impl ConfigType for CliConfig {
     fn default_config() -> &'static str {
         DEFAULT_CLI_CONFIG
@@ -0,0 +1,422 @@
+//! Conventional Commit parsing (<https://www.conventionalcommits.org/>) and the SemVer bump inference that's
+//! derived from it.
+
+use crate::cli::git;
+use anyhow::Result;
+use indexmap::IndexMap;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+static CONVENTIONAL_COMMIT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<type>\w+)(\((?P<scope>[^)]+)\))?(?P<breaking>!)?: (?P<desc>.+)$")
+        .expect("conventional commit regex is valid")
+});
+
+const BREAKING_CHANGE_FOOTER: &str = "BREAKING CHANGE:";
+
+/// A single commit, parsed against the Conventional Commit grammar `type(scope)!: description`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub description: String,
+    /// Set when the subject carries a `!` before the colon, or the body has a `BREAKING CHANGE:` footer.
+    pub breaking: bool,
+}
+
+/// Parses a commit's subject line (and scans its full message for a `BREAKING CHANGE:` footer). Returns `None` for
+/// subjects that don't match the grammar at all; callers decide whether that means "other" or "drop".
+pub fn parse_commit(subject: &str, full_message: &str) -> Option<ConventionalCommit> {
+    let caps = CONVENTIONAL_COMMIT_RE.captures(subject)?;
+
+    let commit_type = caps["type"].to_string();
+    let scope = caps.name("scope").map(|m| m.as_str().to_string());
+    let description = caps["desc"].to_string();
+    let breaking =
+        caps.name("breaking").is_some() || full_message.contains(BREAKING_CHANGE_FOOTER);
+
+    Some(ConventionalCommit {
+        commit_type,
+        scope,
+        description,
+        breaking,
+    })
+}
+
+/// Commits recognized as fixes/maintenance that warrant at least a patch bump, beyond `fix` itself.
+const PATCH_WORTHY_TYPES: &[&str] = &[
+    "fix", "perf", "refactor", "docs", "chore", "test", "build", "ci", "style",
+];
+
+/// Computes the next version from `previous` given the set of commits since it, following SemVer:
+///   - any breaking change bumps major (minor, pre-1.0.0, per SemVer's "anything goes" clause) and zeroes the rest
+///   - else any `feat` bumps minor
+///   - else any recognized `fix`/maintenance type bumps patch
+///   - otherwise there's no bump at all
+pub fn next_version(previous: &semver::Version, commits: &[ConventionalCommit]) -> semver::Version {
+    let any_breaking = commits.iter().any(|c| c.breaking);
+    let any_feature = commits.iter().any(|c| c.commit_type == "feat");
+    let any_patch_worthy = commits
+        .iter()
+        .any(|c| PATCH_WORTHY_TYPES.contains(&c.commit_type.as_str()));
+
+    let mut next = previous.clone();
+
+    if any_breaking {
+        if previous.major == 0 {
+            next.minor += 1;
+            next.patch = 0;
+        } else {
+            next.major += 1;
+            next.minor = 0;
+            next.patch = 0;
+        }
+    } else if any_feature {
+        next.minor += 1;
+        next.patch = 0;
+    } else if any_patch_worthy {
+        next.patch += 1;
+    }
+
+    next.pre = semver::Prerelease::EMPTY;
+    next.build = semver::BuildMetadata::EMPTY;
+
+    next
+}
+
+/// Commits bucketed by Conventional Commit type, in the order they'll typically be rendered.
+#[derive(Debug, Clone, Default)]
+pub struct CommitGroups {
+    pub features: Vec<ConventionalCommit>,
+    pub fixes: Vec<ConventionalCommit>,
+    pub other: Vec<ConventionalCommit>,
+}
+
+impl CommitGroups {
+    fn push(&mut self, commit: ConventionalCommit) {
+        match commit.commit_type.as_str() {
+            "feat" => self.features.push(commit),
+            "fix" => self.fixes.push(commit),
+            _ => self.other.push(commit),
+        }
+    }
+}
+
+/// A single rendered changelog line: the commit's abbreviated hash and its description with the conventional-commit
+/// type prefix already stripped off (non-matching commits keep their raw subject line instead).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CommitEntry {
+    pub hash: String,
+    pub description: String,
+}
+
+/// The changelog section categories, in the order they're rendered. This only sets the output order; which category
+/// a commit lands in is decided by `category_for`, which puts a breaking `feat` under "breaking".
+const CATEGORY_ORDER: &[&str] = &["features", "fixes", "performance", "breaking", "other"];
+
+/// The out-of-the-box heading for a category, used unless overridden via `[changelog.headings]`.
+fn default_heading(category: &str) -> &'static str {
+    match category {
+        "features" => "Features",
+        "fixes" => "Bug Fixes",
+        "performance" => "Performance",
+        "breaking" => "Breaking Changes",
+        _ => "Other",
+    }
+}
+
+/// Which category a parsed commit belongs to. Breaking takes priority over type, since a `feat!` is more notable
+/// for being breaking than for being a feature.
+fn category_for(commit: &ConventionalCommit) -> &'static str {
+    if commit.breaking {
+        return "breaking";
+    }
+
+    match commit.commit_type.as_str() {
+        "feat" => "features",
+        "fix" => "fixes",
+        "perf" => "performance",
+        _ => "other",
+    }
+}
+
+/// Groups `commits` into changelog sections keyed by their (possibly overridden) heading text, in `CATEGORY_ORDER`.
+/// Commits that don't match the Conventional Commit grammar fall into "Other" rather than being dropped. A
+/// category whose override heading is set to an empty string is hidden from the result entirely.
+pub fn group_commits(
+    commits: &[git2::Commit],
+    headings: &HashMap<String, String>,
+) -> IndexMap<String, Vec<CommitEntry>> {
+    let mut by_category: HashMap<&str, Vec<CommitEntry>> = HashMap::new();
+
+    for commit in commits {
+        let subject = git::get_short_message(commit);
+        let full_message = commit.message().unwrap_or_default();
+        let hash = git::get_abbreviated_hash(commit.id());
+
+        let (category, description) = match parse_commit(&subject, full_message) {
+            Some(parsed) => (category_for(&parsed), parsed.description),
+            None => ("other", subject),
+        };
+
+        by_category
+            .entry(category)
+            .or_default()
+            .push(CommitEntry { hash, description });
+    }
+
+    let mut grouped = IndexMap::new();
+    for category in CATEGORY_ORDER {
+        let Some(entries) = by_category.remove(category) else {
+            continue;
+        };
+
+        let heading = headings
+            .get(*category)
+            .map(String::as_str)
+            .unwrap_or_else(|| default_heading(category));
+
+        if heading.is_empty() {
+            continue;
+        }
+
+        grouped.insert(heading.to_string(), entries);
+    }
+
+    grouped
+}
+
+/// A self-contained summary of "what the next release looks like": the previous version, the inferred next
+/// version, and the commits since then grouped by type.
+#[derive(Debug, Clone)]
+pub struct ReleaseDraft {
+    pub previous: semver::Version,
+    pub next: semver::Version,
+    pub groups: CommitGroups,
+}
+
+/// Builds a `ReleaseDraft` from the tag/commit pair returned by [`git::get_commits_after_latest_tag`].
+pub fn build_release_draft(
+    tag_ref: &git2::Reference,
+    commits: &[git2::Commit],
+) -> Result<ReleaseDraft> {
+    let previous = git::parse_tag_version(tag_ref)?;
+
+    let parsed: Vec<ConventionalCommit> = commits
+        .iter()
+        .filter_map(|commit| {
+            parse_commit(
+                &git::get_short_message(commit),
+                commit.message().unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    let next = next_version(&previous, &parsed);
+
+    let mut groups = CommitGroups::default();
+    for commit in parsed {
+        groups.push(commit);
+    }
+
+    Ok(ReleaseDraft {
+        previous,
+        next,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::{Commit, Repository, Signature};
+    use std::{env, fs};
+
+    fn init_repo(name_suffix: &str) -> Repository {
+        let mut path = env::temp_dir();
+        path.push(format!("conventional_group_commits_test_{name_suffix}"));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        Repository::init(&path).unwrap()
+    }
+
+    fn commit_file<'a>(repo: &'a Repository, contents: &str, message: &str) -> Commit<'a> {
+        let workdir = repo.workdir().expect("repo must have a workdir");
+        fs::write(workdir.join("file.txt"), contents).unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("file.txt")).unwrap();
+        index.write().unwrap();
+
+        let tree_oid = index.write_tree().unwrap();
+        let tree = repo.find_tree(tree_oid).unwrap();
+
+        let sig = Signature::now("Test User", "test@example.com").unwrap();
+
+        let parents: Vec<Commit> = match repo.head() {
+            Ok(head) => vec![head.peel_to_commit().unwrap()],
+            Err(_) => Vec::new(),
+        };
+        let parent_refs: Vec<&Commit> = parents.iter().collect();
+
+        let commit_oid = repo
+            .commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs)
+            .unwrap();
+
+        repo.find_commit(commit_oid).unwrap()
+    }
+
+    #[test]
+    fn group_commits_buckets_by_conventional_type() {
+        let repo = init_repo("buckets_by_type");
+        let feature = commit_file(&repo, "a", "feat: add dark mode");
+        let fix = commit_file(&repo, "b", "fix: correct off-by-one");
+
+        let grouped = group_commits(&[feature, fix], &HashMap::new());
+
+        let headings: Vec<&str> = grouped.keys().map(String::as_str).collect();
+        assert_eq!(headings, vec!["Features", "Bug Fixes"]);
+        assert_eq!(grouped["Features"][0].description, "add dark mode");
+        assert_eq!(grouped["Bug Fixes"][0].description, "correct off-by-one");
+    }
+
+    #[test]
+    fn group_commits_puts_breaking_changes_ahead_of_type() {
+        let repo = init_repo("breaking_takes_priority");
+        let commit = commit_file(&repo, "a", "feat(api)!: drop v1 endpoints");
+
+        let grouped = group_commits(&[commit], &HashMap::new());
+
+        assert_eq!(
+            grouped.keys().collect::<Vec<_>>(),
+            vec!["Breaking Changes"]
+        );
+        assert_eq!(grouped["Breaking Changes"][0].description, "drop v1 endpoints");
+    }
+
+    #[test]
+    fn group_commits_falls_back_to_other_for_non_conventional_subjects() {
+        let repo = init_repo("fallback_other");
+        let commit = commit_file(&repo, "a", "tweak some stuff");
+
+        let grouped = group_commits(&[commit], &HashMap::new());
+
+        assert_eq!(grouped.keys().collect::<Vec<_>>(), vec!["Other"]);
+        assert_eq!(grouped["Other"][0].description, "tweak some stuff");
+    }
+
+    #[test]
+    fn group_commits_honors_heading_overrides_and_hides_empty_ones() {
+        let repo = init_repo("heading_overrides");
+        let feature = commit_file(&repo, "a", "feat: add dark mode");
+        let fix = commit_file(&repo, "b", "fix: correct off-by-one");
+
+        let mut headings = HashMap::new();
+        headings.insert("features".to_string(), "New Stuff".to_string());
+        headings.insert("fixes".to_string(), "".to_string());
+
+        let grouped = group_commits(&[feature, fix], &headings);
+
+        assert_eq!(grouped.keys().collect::<Vec<_>>(), vec!["New Stuff"]);
+    }
+
+    #[test]
+    fn parses_a_simple_feature_commit() {
+        let commit = parse_commit("feat: add dark mode", "feat: add dark mode").unwrap();
+        assert_eq!(commit.commit_type, "feat");
+        assert_eq!(commit.scope, None);
+        assert_eq!(commit.description, "add dark mode");
+        assert!(!commit.breaking);
+    }
+
+    #[test]
+    fn parses_scope_and_bang_breaking_marker() {
+        let commit = parse_commit(
+            "fix(auth)!: reject expired tokens",
+            "fix(auth)!: reject expired tokens",
+        )
+        .unwrap();
+        assert_eq!(commit.commit_type, "fix");
+        assert_eq!(commit.scope, Some("auth".to_string()));
+        assert!(commit.breaking);
+    }
+
+    #[test]
+    fn detects_breaking_change_footer() {
+        let message = "feat: rework config loading\n\nBREAKING CHANGE: config keys were renamed";
+        let commit = parse_commit("feat: rework config loading", message).unwrap();
+        assert!(commit.breaking);
+    }
+
+    #[test]
+    fn non_conventional_subjects_do_not_parse() {
+        assert!(parse_commit("fix stuff", "fix stuff").is_none());
+    }
+
+    #[test]
+    fn breaking_change_bumps_major_post_1_0() {
+        let previous = semver::Version::parse("1.4.2").unwrap();
+        let commits = vec![ConventionalCommit {
+            commit_type: "feat".to_string(),
+            scope: None,
+            description: "".to_string(),
+            breaking: true,
+        }];
+
+        assert_eq!(next_version(&previous, &commits), semver::Version::new(2, 0, 0));
+    }
+
+    #[test]
+    fn breaking_change_bumps_minor_pre_1_0() {
+        let previous = semver::Version::parse("0.4.2").unwrap();
+        let commits = vec![ConventionalCommit {
+            commit_type: "feat".to_string(),
+            scope: None,
+            description: "".to_string(),
+            breaking: true,
+        }];
+
+        assert_eq!(next_version(&previous, &commits), semver::Version::new(0, 5, 0));
+    }
+
+    #[test]
+    fn feature_bumps_minor() {
+        let previous = semver::Version::parse("1.4.2").unwrap();
+        let commits = vec![ConventionalCommit {
+            commit_type: "feat".to_string(),
+            scope: None,
+            description: "".to_string(),
+            breaking: false,
+        }];
+
+        assert_eq!(next_version(&previous, &commits), semver::Version::new(1, 5, 0));
+    }
+
+    #[test]
+    fn fix_bumps_patch() {
+        let previous = semver::Version::parse("1.4.2").unwrap();
+        let commits = vec![ConventionalCommit {
+            commit_type: "fix".to_string(),
+            scope: None,
+            description: "".to_string(),
+            breaking: false,
+        }];
+
+        assert_eq!(next_version(&previous, &commits), semver::Version::new(1, 4, 3));
+    }
+
+    #[test]
+    fn unrecognized_commits_do_not_bump() {
+        let previous = semver::Version::parse("1.4.2").unwrap();
+        let commits = vec![ConventionalCommit {
+            commit_type: "wip".to_string(),
+            scope: None,
+            description: "".to_string(),
+            breaking: false,
+        }];
+
+        assert_eq!(next_version(&previous, &commits), previous);
+    }
+}
@@ -7,9 +7,21 @@ use rootcause::{
 };
 
 #[derive(Debug, Clone, Copy)]
-pub struct TreeFormatter;
+pub struct TreeFormatter {
+    /// Whether the formatter is allowed to emit ANSI color codes. Resolved once, up front, from the
+    /// configured `--color`/`color` choice so we don't re-check the terminal/`NO_COLOR` on every line.
+    color_enabled: bool,
+}
 
 impl TreeFormatter {
+    fn colorize(&self, s: &str) -> String {
+        if self.color_enabled {
+            s.magenta().to_string()
+        } else {
+            s.to_string()
+        }
+    }
+
     fn linear_chain(
         mut r: ReportRef<'_, Dynamic, Uncloneable, Local>,
     ) -> Vec<ReportRef<'_, Dynamic, Uncloneable, Local>> {
@@ -44,6 +56,7 @@ impl TreeFormatter {
     }
 
     fn format_one(
+        &self,
         f: &mut std::fmt::Formatter<'_>,
         r: ReportRef<'_, Dynamic, Uncloneable, Local>,
         i: usize,
@@ -78,8 +91,8 @@ impl TreeFormatter {
 
         // 1) Context
         let ctx = format!("{}", r.format_current_context_unhooked());
-        let head_col = head.magenta().to_string();
-        let head_cont_col = head_cont.magenta().to_string();
+        let head_col = self.colorize(head);
+        let head_cont_col = self.colorize(head_cont);
         Self::write_prefixed_lines(f, &head_col, &head_cont_col, &ctx)?;
 
         // 2) Attachments (if present)
@@ -93,14 +106,14 @@ impl TreeFormatter {
             };
             let attachment_cont = if is_final_line { "    " } else { "│   " };
             let text = format!("{attachment}");
-            let attachment_head_col = attachment_head.magenta().to_string();
-            let attachment_cont_col = attachment_cont.magenta().to_string();
+            let attachment_head_col = self.colorize(attachment_head);
+            let attachment_cont_col = self.colorize(attachment_cont);
             Self::write_prefixed_lines(f, &attachment_head_col, &attachment_cont_col, &text)?;
         }
 
         // Optional blank “tree spacer” line between nodes (except after last)
         if !is_last {
-            writeln!(f, "{}", "┆".magenta())?;
+            writeln!(f, "{}", self.colorize("┆"))?;
         }
 
         Ok(())
@@ -121,7 +134,7 @@ impl ReportFormatter for TreeFormatter {
 
             let chain = Self::linear_chain(*root);
             for (i, r) in chain.iter().copied().enumerate() {
-                Self::format_one(f, r, i, chain.len(), report_formatting_function)?;
+                self.format_one(f, r, i, chain.len(), report_formatting_function)?;
             }
         }
         Ok(())
@@ -130,17 +143,22 @@ impl ReportFormatter for TreeFormatter {
 
 /// This changes the default rootcause formatter into one that's a bit more aesthetically pleasing, using box drawing
 /// to create a tree instead of using bullet points.
-pub fn alter_error_formatter(debug: bool) {
+///
+/// `color_enabled` is the already-resolved choice (from `ColorChoice::Auto`/`Always`/`Never`); the formatter just
+/// stores it and skips colorization when it's `false`.
+pub fn alter_error_formatter(debug: bool, color_enabled: bool) {
+    let formatter = TreeFormatter { color_enabled };
+
     if debug {
         Hooks::new()
-            .report_formatter(TreeFormatter)
+            .report_formatter(formatter)
             .install()
             .expect("failed to install rootcause hooks");
         return;
     }
 
     Hooks::new_without_locations()
-        .report_formatter(TreeFormatter)
+        .report_formatter(formatter)
         .install()
         .expect("failed to install rootcause hooks");
 }
@@ -0,0 +1,448 @@
+//! Pluggable release-hosting backends ("forges"), so the rest of the CLI doesn't have to know whether the
+//! detected remote is GitHub, a self-hosted Gitea/Forgejo instance, or GitLab.
+
+use crate::cli::git;
+use anyhow::{Context, Result, anyhow, bail};
+use bytes::Bytes;
+use octocrab::Octocrab;
+use serde::{Deserialize, de};
+use strum_macros::EnumString;
+
+/// Which forge software a remote talks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(ascii_case_insensitive, serialize_all = "lowercase")]
+pub enum ForgeKind {
+    Github,
+    Gitea,
+    Forgejo,
+    Gitlab,
+}
+
+const FORGE_KINDS: [&str; 4] = ["github", "gitea", "forgejo", "gitlab"];
+
+fn deserialize_forge_kind<'de, D>(deserializer: D) -> Result<ForgeKind, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    use std::str::FromStr;
+
+    let s = String::deserialize(deserializer)?;
+
+    ForgeKind::from_str(&s).map_err(|_| {
+        let hint = crate::cli::suggest::did_you_mean(&s, FORGE_KINDS);
+
+        match hint {
+            Some(hint) => de::Error::custom(format!("`{s}` is not a recognized forge type; {hint}")),
+            None => de::Error::custom(format!("`{s}` is not a recognized forge type")),
+        }
+    })
+}
+
+/// Per-remote API configuration, keyed by remote host in [`crate::cli::conf::CliConfig::forges`]: which forge it
+/// is, where its API lives (for self-hosted instances), and how to authenticate.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ForgeEndpoint {
+    #[serde(rename = "type", deserialize_with = "deserialize_forge_kind")]
+    pub kind: ForgeKind,
+
+    /// Base API endpoint, e.g. `https://git.mycompany.com`. Unset for the public github.com/gitlab.com defaults.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// Literal auth token. Prefer `token_env` so real secrets stay out of the config file; only one of the two
+    /// should be set, and `token` wins if both are.
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Name of an environment variable to read the auth token from at runtime.
+    #[serde(default)]
+    pub token_env: Option<String>,
+}
+
+impl ForgeEndpoint {
+    pub fn resolve_token(&self) -> Result<String> {
+        if let Some(token) = &self.token {
+            return Ok(token.clone());
+        }
+
+        if let Some(var) = &self.token_env {
+            return std::env::var(var).with_context(|| {
+                format!("environment variable `{var}` referenced by `token_env` is not set")
+            });
+        }
+
+        bail!("forge endpoint configuration has neither `token` nor `token_env` set");
+    }
+}
+
+/// A created release, as handed back by whichever forge created it.
+#[derive(Debug, Clone)]
+pub struct ReleaseHandle {
+    pub id: u64,
+    pub tag: String,
+    pub html_url: String,
+}
+
+/// A release-hosting backend: create a release, then attach assets to it.
+///
+/// Every implementation builds and tears down its own Tokio runtime per call, the same pattern the GitHub-only
+/// release path used before this trait existed, so callers stay entirely synchronous.
+pub trait Forge {
+    fn create_release(
+        &self,
+        tag: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        draft: bool,
+    ) -> Result<ReleaseHandle>;
+
+    fn upload_asset(&self, release: &ReleaseHandle, name: &str, bytes: Bytes) -> Result<()>;
+}
+
+fn tokio_runtime() -> Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("failed to create Tokio runtime")
+}
+
+/// Wraps the GitHub REST API via `octocrab`, the only forge this tool originally supported.
+pub struct GitHubForge {
+    pub token: String,
+    pub owner: String,
+    pub repo: String,
+}
+
+impl Forge for GitHubForge {
+    fn create_release(
+        &self,
+        tag: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        draft: bool,
+    ) -> Result<ReleaseHandle> {
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = Octocrab::builder()
+                .personal_token(self.token.clone())
+                .build()
+                .context("failed to build GitHub client")?;
+
+            let created = client
+                .repos(&self.owner, &self.repo)
+                .releases()
+                .create(tag)
+                .name(name)
+                .body(body)
+                .prerelease(prerelease)
+                .draft(draft)
+                .send()
+                .await
+                .context("failure while attempting to create GitHub release")?;
+
+            Ok(ReleaseHandle {
+                id: created.id.0,
+                tag: tag.to_string(),
+                html_url: created.html_url.to_string(),
+            })
+        })
+    }
+
+    fn upload_asset(&self, release: &ReleaseHandle, name: &str, bytes: Bytes) -> Result<()> {
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = Octocrab::builder()
+                .personal_token(self.token.clone())
+                .build()
+                .context("failed to build GitHub client")?;
+
+            client
+                .repos(&self.owner, &self.repo)
+                .releases()
+                .upload_asset(release.id, name, bytes)
+                .send()
+                .await
+                .context(format!("GitHub upload_asset call failed for {name}"))?;
+
+            Ok(())
+        })
+    }
+}
+
+/// Wraps the Gitea/Forgejo REST API (the two are API-compatible for our purposes), hitting
+/// `/api/v1/repos/{owner}/{repo}/releases` directly since neither has a Rust client as mature as `octocrab`.
+pub struct GiteaForge {
+    pub endpoint: String,
+    pub token: String,
+    pub owner: String,
+    pub repo: String,
+}
+
+impl GiteaForge {
+    fn releases_url(&self) -> String {
+        format!(
+            "{}/api/v1/repos/{}/{}/releases",
+            self.endpoint.trim_end_matches('/'),
+            self.owner,
+            self.repo
+        )
+    }
+}
+
+impl Forge for GiteaForge {
+    fn create_release(
+        &self,
+        tag: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        draft: bool,
+    ) -> Result<ReleaseHandle> {
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = reqwest::Client::new();
+
+            let created: serde_json::Value = client
+                .post(self.releases_url())
+                .bearer_auth(&self.token)
+                .json(&serde_json::json!({
+                    "tag_name": tag,
+                    "name": name,
+                    "body": body,
+                    "prerelease": prerelease,
+                    "draft": draft,
+                }))
+                .send()
+                .await
+                .context("failure while attempting to create Gitea/Forgejo release")?
+                .error_for_status()
+                .context("Gitea/Forgejo rejected the release creation request")?
+                .json()
+                .await
+                .context("could not parse Gitea/Forgejo release response")?;
+
+            let id = created["id"]
+                .as_u64()
+                .ok_or_else(|| anyhow!("Gitea/Forgejo release response had no numeric `id`"))?;
+            let html_url = created["html_url"].as_str().unwrap_or_default().to_string();
+
+            Ok(ReleaseHandle {
+                id,
+                tag: tag.to_string(),
+                html_url,
+            })
+        })
+    }
+
+    fn upload_asset(&self, release: &ReleaseHandle, name: &str, bytes: Bytes) -> Result<()> {
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = reqwest::Client::new();
+
+            let part = reqwest::multipart::Part::bytes(bytes.to_vec()).file_name(name.to_string());
+            let form = reqwest::multipart::Form::new().part("attachment", part);
+
+            client
+                .post(format!("{}/{}/assets", self.releases_url(), release.id))
+                .bearer_auth(&self.token)
+                .query(&[("name", name)])
+                .multipart(form)
+                .send()
+                .await
+                .context(format!("Gitea/Forgejo upload_asset call failed for {name}"))?
+                .error_for_status()
+                .context("Gitea/Forgejo rejected the asset upload")?;
+
+            Ok(())
+        })
+    }
+}
+
+/// Wraps the GitLab REST API, hitting `/api/v4/projects/{owner%2Frepo}/releases` — GitLab's own docs recommend the
+/// URL-encoded `owner/repo` path when you don't already have the numeric project ID.
+///
+/// GitLab models release assets as *links* to separately-uploaded files rather than binary attachments on the
+/// release itself, so `upload_asset` here is a two-step dance: upload the file to the project, then link it.
+pub struct GitLabForge {
+    pub endpoint: String,
+    pub token: String,
+    pub owner: String,
+    pub repo: String,
+}
+
+impl GitLabForge {
+    fn project_path(&self) -> String {
+        format!("{}%2F{}", self.owner, self.repo)
+    }
+
+    fn project_url(&self) -> String {
+        format!(
+            "{}/api/v4/projects/{}",
+            self.endpoint.trim_end_matches('/'),
+            self.project_path()
+        )
+    }
+}
+
+impl Forge for GitLabForge {
+    fn create_release(
+        &self,
+        tag: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        draft: bool,
+    ) -> Result<ReleaseHandle> {
+        // GitLab releases have no first-class prerelease or draft flag; left for a future request to surface if
+        // GitLab ever exposes one.
+        let _ = prerelease;
+        let _ = draft;
+
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = reqwest::Client::new();
+
+            let created: serde_json::Value = client
+                .post(format!("{}/releases", self.project_url()))
+                .header("PRIVATE-TOKEN", &self.token)
+                .json(&serde_json::json!({
+                    "tag_name": tag,
+                    "name": name,
+                    "description": body,
+                }))
+                .send()
+                .await
+                .context("failure while attempting to create GitLab release")?
+                .error_for_status()
+                .context("GitLab rejected the release creation request")?
+                .json()
+                .await
+                .context("could not parse GitLab release response")?;
+
+            let html_url = created["_links"]["self"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(ReleaseHandle {
+                id: 0,
+                tag: tag.to_string(),
+                html_url,
+            })
+        })
+    }
+
+    fn upload_asset(&self, release: &ReleaseHandle, name: &str, bytes: Bytes) -> Result<()> {
+        let rt = tokio_runtime()?;
+
+        rt.block_on(async {
+            let client = reqwest::Client::new();
+
+            let part = reqwest::multipart::Part::bytes(bytes.to_vec()).file_name(name.to_string());
+            let form = reqwest::multipart::Form::new().part("file", part);
+
+            let uploaded: serde_json::Value = client
+                .post(format!("{}/uploads", self.project_url()))
+                .header("PRIVATE-TOKEN", &self.token)
+                .multipart(form)
+                .send()
+                .await
+                .context(format!("GitLab upload for asset {name} failed"))?
+                .error_for_status()
+                .context("GitLab rejected the asset upload")?
+                .json()
+                .await
+                .context("could not parse GitLab upload response")?;
+
+            let url = uploaded["url"]
+                .as_str()
+                .ok_or_else(|| anyhow!("GitLab upload response had no `url`"))?;
+            let full_url = format!("{}{}", self.endpoint.trim_end_matches('/'), url);
+
+            client
+                .post(format!(
+                    "{}/releases/{}/assets/links",
+                    self.project_url(),
+                    release.tag
+                ))
+                .header("PRIVATE-TOKEN", &self.token)
+                .json(&serde_json::json!({ "name": name, "url": full_url }))
+                .send()
+                .await
+                .context(format!("GitLab release link creation failed for {name}"))?
+                .error_for_status()
+                .context("GitLab rejected the release link")?;
+
+            Ok(())
+        })
+    }
+}
+
+/// Selects a [`Forge`] for `remote`, preferring an explicit `[forges."<host>"]` config block and falling back to
+/// the GitHub backend (this tool's only backend before this config block existed) when nothing matches and the
+/// host doesn't look like another forge.
+pub fn select_forge(
+    remote: &git::Remote,
+    forges: &std::collections::HashMap<String, ForgeEndpoint>,
+    default_github_token: &str,
+) -> Result<Box<dyn Forge>> {
+    if let Some(endpoint) = forges.get(&remote.host) {
+        let token = endpoint
+            .resolve_token()
+            .context("could not resolve forge auth token")?;
+        let api_endpoint = endpoint
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://{}", remote.host));
+
+        return Ok(match endpoint.kind {
+            ForgeKind::Github => Box::new(GitHubForge {
+                token,
+                owner: remote.owner.clone(),
+                repo: remote.name.clone(),
+            }),
+            ForgeKind::Gitea | ForgeKind::Forgejo => Box::new(GiteaForge {
+                endpoint: api_endpoint,
+                token,
+                owner: remote.owner.clone(),
+                repo: remote.name.clone(),
+            }),
+            ForgeKind::Gitlab => Box::new(GitLabForge {
+                endpoint: api_endpoint,
+                token,
+                owner: remote.owner.clone(),
+                repo: remote.name.clone(),
+            }),
+        });
+    }
+
+    match remote.provider {
+        git::Provider::GitLab => bail!(
+            "remote '{}' looks like GitLab, but no matching `[forges.\"{}\"]` config block was found",
+            remote.host,
+            remote.host
+        ),
+        git::Provider::Gitea => bail!(
+            "remote '{}' looks like Gitea/Forgejo, but no matching `[forges.\"{}\"]` config block was found",
+            remote.host,
+            remote.host
+        ),
+        git::Provider::Bitbucket => bail!(
+            "remote '{}' looks like Bitbucket, which has no release backend",
+            remote.host
+        ),
+        _ => Ok(Box::new(GitHubForge {
+            token: default_github_token.to_string(),
+            owner: remote.owner.clone(),
+            repo: remote.name.clone(),
+        })),
+    }
+}
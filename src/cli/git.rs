@@ -2,10 +2,79 @@ use crate::err;
 use anyhow::{Context, Result, anyhow, bail};
 use polyfmt::debug;
 
-/// A convenience function for getting the organization name and repo name for a project hosted on Github.
+/// Options controlling which tags are considered when scanning for "the latest" one.
 ///
-/// We parse the URL here because organization/repo combination is really a Github concept not so much a Git concept.
-pub fn get_org_and_repo(repo: &git2::Repository) -> Result<(String, String)> {
+/// Defaults (`pattern: None`, `strip_prefix: None`, `skip_prereleases: false`) reproduce the historical,
+/// unconditional behavior: every `refs/tags/*` entry is tried, with only a leading `v` stripped before SemVer
+/// parsing, and prereleases are treated the same as any other version.
+#[derive(Debug, Clone, Default)]
+pub struct TagSelection {
+    /// Restricts candidate tags to those whose shorthand matches this glob (e.g. `api-v*` for a monorepo).
+    pub pattern: Option<glob::Pattern>,
+
+    /// A literal prefix to strip before SemVer parsing, on top of the `v` we always strip (e.g. `"api-v"` turns
+    /// `api-v1.2.3` into `1.2.3`). Tags missing the prefix are skipped.
+    pub strip_prefix: Option<String>,
+
+    /// Excludes versions with a non-empty `pre` field (e.g. `2.0.0-rc.1`) from consideration as "latest".
+    pub skip_prereleases: bool,
+}
+
+/// The forge hosting a remote. Inferred from the remote's hostname, since there's no protocol-level way to ask
+/// "what kind of server are you".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Provider {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    Gitea,
+    Unknown,
+}
+
+impl Provider {
+    /// Infers a provider from a bare hostname (e.g. "github.com", "gitlab.mycompany.com"). Self-hosted instances
+    /// that don't advertise their software in the hostname will come back `Unknown`; callers that know better
+    /// (e.g. from configuration) should skip this and construct the `Provider` directly instead.
+    fn from_host(host: &str) -> Self {
+        let host = host.to_ascii_lowercase();
+
+        if host.contains("github") {
+            Provider::GitHub
+        } else if host.contains("gitlab") {
+            Provider::GitLab
+        } else if host.contains("bitbucket") {
+            Provider::Bitbucket
+        } else if host.contains("gitea") || host.contains("codeberg") {
+            Provider::Gitea
+        } else {
+            Provider::Unknown
+        }
+    }
+}
+
+/// A parsed `origin` remote: which forge it lives on, and the owner/name of the repo on that forge.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Remote {
+    pub host: String,
+    pub owner: String,
+    pub name: String,
+    pub provider: Provider,
+}
+
+/// Parses the `origin` remote into a forge-agnostic `Remote`, covering GitHub, GitLab, Bitbucket, Gitea/Forgejo,
+/// and self-hosted instances of any of the above.
+///
+/// Rather than anchoring on a literal host like `github.com`, we strip the scheme/userinfo off the remote URL and
+/// treat whatever's left as `host/.../owner/name`, taking the first segment as the host and the last two as
+/// owner/name. This covers the URL forms we've always supported:
+///   - `git@host:owner/repo.git`
+///   - `https://host/owner/repo.git`
+///   - `ssh://git@host/owner/repo`
+///
+/// A self-hosted instance whose hostname doesn't give away what forge software it's running still comes back with
+/// `Provider::Unknown` here; [`crate::cli::forge::select_forge`]'s `[forges."<host>"]` config block is the actual
+/// override hook for that case, since it also carries the endpoint/token the `Unknown` provider alone wouldn't.
+pub fn parse_remote(repo: &git2::Repository) -> Result<Remote> {
     let remote = repo.find_remote("origin").context(err!(
         "Could not find remote 'origin'; \
             remote origin required in order to parse organization/repo"
@@ -14,29 +83,46 @@ pub fn get_org_and_repo(repo: &git2::Repository) -> Result<(String, String)> {
     let url = remote.url().context(err!("Remote has no URL"))?;
     let trimmed = url.trim_end_matches(".git");
 
-    // Split on both ':' and '/' so we cover:
-    // - git@github.com:org/repo.git
-    // - https://github.com/org/repo.git
-    // - ssh://git@github.com/org/repo.git
-    let parts: Vec<&str> = trimmed.split(['/', ':']).collect();
+    // Strip the scheme (e.g. "https://", "ssh://") if present.
+    let (has_scheme, rest) = match trimmed.split_once("://") {
+        Some((_, rest)) => (true, rest),
+        None => (false, trimmed),
+    };
 
-    // Next we find the segment containing "github.com" so we can just count from there.
-    let idx = parts
-        .iter()
-        .position(|s| s.contains("github.com"))
-        .ok_or_else(|| anyhow!(err!("URL '{}' does not look like a GitHub URL", url)))?;
+    // scp-like syntax ("git@host:owner/repo") uses ':' to separate the host from the path; normalize it to '/' so
+    // both forms reduce to "[user@]host/owner/repo".
+    let rest = if has_scheme {
+        rest.to_string()
+    } else {
+        rest.replacen(':', "/", 1)
+    };
 
-    if idx + 2 >= parts.len() {
+    // Strip userinfo (e.g. "git@host/owner/repo" -> "host/owner/repo").
+    let rest = match rest.split_once('@') {
+        Some((_, after)) => after,
+        None => &rest,
+    };
+
+    let parts: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+
+    if parts.len() < 3 {
         return Err(anyhow!(err!(
-            "Could not parse organization and repo name from '{}'",
+            "Could not parse host/owner/repo from remote URL '{}'",
             url
         )));
     }
 
-    let org = parts[idx + 1].to_string();
-    let repo = parts[idx + 2].to_string();
-
-    Ok((org, repo))
+    let host = parts[0].to_string();
+    let owner = parts[parts.len() - 2].to_string();
+    let name = parts[parts.len() - 1].to_string();
+    let provider = Provider::from_host(&host);
+
+    Ok(Remote {
+        host,
+        owner,
+        name,
+        provider,
+    })
 }
 
 /// Attempt to determine the default branch using the symbolic reference
@@ -185,48 +271,20 @@ fn resolve_default_base(repo: &git2::Repository) -> Result<(String, git2::Oid)>
     Ok((base_name, base_oid))
 }
 
-/// Gather commits on the repository’s default branch that occurred
-/// *after the most recent SemVer tag*, using GitHub-style comparison semantics.
-///
-/// ## What this does
-///
-/// This mirrors how GitHub computes the “Compare: <tag>...<branch>” view:
-///
-/// 1. **Find all tags that look like SemVer**
-///    - We ignore tags that don’t parse as SemVer (e.g. "test", "alpha", etc.)
-///    - We select the *numerically highest* SemVer (`max_by`), regardless of
-///      which branch it appears on.
-///
-/// 2. **Find the commit the tag points to**
-///    - Git tags can point directly to a commit or to an annotated tag object.
-///      `peel_to_commit()` resolves that automatically.
-///
-/// 3. **Determine the repo’s default branch**
-///    - We use `resolve_default_base()` to determine the default branch name
-///      and its HEAD commit (usually "refs/heads/main").
-///
-/// 4. **Find the merge-base between the tag and the default branch**
-///    - The merge-base is the “best common ancestor” of the two commits.
-///    - GitHub’s `A...B` syntax shows commits reachable from `B` but *not* from
-///      the merge-base.
-///
-/// 5. **Walk the commit history of the default branch**
-///    - Starting at its HEAD
-///    - Hide the merge-base (if it exists)
-///    - Collect all commits since that point
-///
-/// ## Returned values
-///
-/// The function returns:
-///
-/// - **The reference for the latest SemVer tag**
-/// - **A list of commits on the default branch since that tag**
-pub fn get_commits_after_latest_tag<'repo>(
+/// Finds every tag under `refs/tags/*` that parses as SemVer (allowing an optional leading `v`) and returns
+/// whichever is numerically highest, regardless of which branch it appears on.
+fn find_latest_semver_tag<'repo>(
     repo: &'repo git2::Repository,
-) -> Result<(git2::Reference<'repo>, Vec<git2::Commit<'repo>>)> {
-    //
-    // ---- 1. Collect all SemVer-looking tags ----
-    //
+) -> Result<(semver::Version, git2::Reference<'repo>)> {
+    find_latest_semver_tag_matching(repo, &TagSelection::default())
+}
+
+/// Like [`find_latest_semver_tag`], but lets the caller restrict candidates to a glob pattern, strip a
+/// monorepo-style prefix before parsing, and/or exclude prereleases from "latest" selection.
+fn find_latest_semver_tag_matching<'repo>(
+    repo: &'repo git2::Repository,
+    selection: &TagSelection,
+) -> Result<(semver::Version, git2::Reference<'repo>)> {
     let refs = repo
         .references_glob("refs/tags/*")
         .context("could not retrieve tags")?;
@@ -242,8 +300,26 @@ pub fn get_commits_after_latest_tag<'repo>(
             continue;
         };
 
+        if let Some(pattern) = &selection.pattern
+            && !pattern.matches(name)
+        {
+            debug!("Skipping tag {:?} not matching pattern {:?}", name, pattern.as_str());
+            continue;
+        }
+
+        let unprefixed = match &selection.strip_prefix {
+            Some(prefix) => match name.strip_prefix(prefix.as_str()) {
+                Some(rest) => rest,
+                None => {
+                    debug!("Skipping tag {:?} missing prefix {:?}", name, prefix);
+                    continue;
+                }
+            },
+            None => name,
+        };
+
         // Allow "v1.2.3" or "1.2.3"
-        let semver_str = name.strip_prefix('v').unwrap_or(name);
+        let semver_str = unprefixed.strip_prefix('v').unwrap_or(unprefixed);
 
         // Only include valid SemVer tags
         let Ok(ver) = semver::Version::parse(semver_str) else {
@@ -251,65 +327,132 @@ pub fn get_commits_after_latest_tag<'repo>(
             continue;
         };
 
+        if selection.skip_prereleases && !ver.pre.is_empty() {
+            debug!("Skipping prerelease tag {:?}", reference.name());
+            continue;
+        }
+
         tags.push((ver, reference));
     }
 
-    // No SemVer tags found → cannot compute compare range
-    let Some((latest_ver, latest_tag_ref)) = tags.into_iter().max_by(|(a, _), (b, _)| a.cmp(b))
-    else {
-        bail!("no semver tags found");
-    };
+    tags.into_iter()
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .ok_or_else(|| anyhow!(err!("no semver tags found")))
+}
 
-    //
-    // ---- 2. Peel the tag to the commit it ultimately refers to ----
-    //
-    let tag_commit = latest_tag_ref.peel_to_commit().with_context(|| {
-        format!(
-            "could not peel tag {}",
-            latest_tag_ref.name().unwrap_or("?")
-        )
-    })?;
+/// A reference to resolve a compare range against: a tag, a branch, a raw commit, an arbitrary ref name, or one of
+/// the two "figure it out for me" shorthands this tool already relied on before ranges were configurable.
+#[derive(Debug, Clone)]
+pub enum GitReference {
+    Tag(String),
+    Branch(String),
+    FullCommit(git2::Oid),
+    NamedRef(String),
+    DefaultBranch,
+    LatestSemverTag(TagSelection),
+}
 
-    let tag_oid = tag_commit.id();
-    debug!("Latest semver tag chosen: {} @ {}", latest_ver, tag_oid);
+impl GitReference {
+    /// Resolves this reference to a commit.
+    ///
+    /// For the ref-name variants, resolution first tries the name as a full ref. `NamedRef` then tries
+    /// `refs/tags/<name>`, then `refs/heads/<name>`, then `refs/remotes/origin/<name>` — the same local vs.
+    /// remote-tracking fallback `resolve_default_base` already does for the default branch. `Tag` only tries
+    /// `refs/tags/<name>`, and `Branch` only `refs/heads/<name>` then `refs/remotes/origin/<name>`.
+    fn resolve<'repo>(&self, repo: &'repo git2::Repository) -> Result<git2::Commit<'repo>> {
+        match self {
+            GitReference::FullCommit(oid) => repo
+                .find_commit(*oid)
+                .with_context(|| format!("could not find commit '{oid}'")),
+            GitReference::DefaultBranch => {
+                let (base_name, base_oid) = resolve_default_base(repo)?;
+                repo.find_commit(base_oid)
+                    .with_context(|| format!("could not find default branch commit '{base_name}'"))
+            }
+            GitReference::LatestSemverTag(selection) => {
+                let (_, tag_ref) = find_latest_semver_tag_matching(repo, selection)?;
+                tag_ref
+                    .peel_to_commit()
+                    .with_context(|| format!("could not peel tag '{}'", tag_ref.name().unwrap_or("?")))
+            }
+            GitReference::Tag(name) => resolve_named_ref(repo, name, &["refs/tags/"]),
+            GitReference::Branch(name) => {
+                resolve_named_ref(repo, name, &["refs/heads/", "refs/remotes/origin/"])
+            }
+            GitReference::NamedRef(name) => resolve_named_ref(
+                repo,
+                name,
+                &["refs/tags/", "refs/heads/", "refs/remotes/origin/"],
+            ),
+        }
+    }
+}
+
+/// Tries `name` as a full ref first, then each of `prefixes` joined with `name`, peeling whichever resolves first
+/// to a commit.
+fn resolve_named_ref<'repo>(
+    repo: &'repo git2::Repository,
+    name: &str,
+    prefixes: &[&str],
+) -> Result<git2::Commit<'repo>> {
+    let mut tried = vec![name.to_string()];
+
+    if let Ok(reference) = repo.find_reference(name) {
+        return reference
+            .peel_to_commit()
+            .with_context(|| format!("could not peel '{name}' to a commit"));
+    }
+
+    for prefix in prefixes {
+        let candidate = format!("{prefix}{name}");
+
+        if let Ok(reference) = repo.find_reference(&candidate) {
+            return reference
+                .peel_to_commit()
+                .with_context(|| format!("could not peel '{candidate}' to a commit"));
+        }
+
+        tried.push(candidate);
+    }
+
+    bail!(
+        "could not resolve reference '{}'; tried {}",
+        name,
+        tried.join(", ")
+    );
+}
+
+/// Collects the commits reachable from `head` but not from `base`, mirroring GitHub's `base...head` three-dot
+/// compare semantics:
+///   - Resolve both `base` and `head` to commits
+///   - Find their merge-base (the "best common ancestor"), if any
+///   - Walk backwards from `head`, hiding the merge-base, collecting everything in between
+pub fn get_commits_between<'repo>(
+    repo: &'repo git2::Repository,
+    base: GitReference,
+    head: GitReference,
+) -> Result<Vec<git2::Commit<'repo>>> {
+    let base_commit = base
+        .resolve(repo)
+        .with_context(|| format!("could not resolve base reference {base:?}"))?;
+    let head_commit = head
+        .resolve(repo)
+        .with_context(|| format!("could not resolve head reference {head:?}"))?;
+
+    let merge_base = repo.merge_base(base_commit.id(), head_commit.id()).ok();
 
-    //
-    // ---- 3. Determine the repository’s default branch ----
-    //
-    // Usually resolves to something like:
-    //   ("refs/heads/main", <oid>)
-    //
-    let (base_name, base_oid) = resolve_default_base(repo)?;
-    debug!("Default base resolved to {} @ {}", base_name, base_oid);
-
-    //
-    // ---- 4. Find merge-base of <tag> and <default branch> ----
-    //
-    // If the tag is from an entirely unrelated branch, merge-base may not exist.
-    //
-    let merge_base = repo.merge_base(tag_oid, base_oid).ok();
-
-    //
-    // ---- 5. Walk commits on the default branch since the merge-base ----
-    //
-    // This reproduces GitHub’s "A...B" behavior (three-dot syntax):
-    //   - Start at B (the default branch)
-    //   - Exclude the merge-base
-    //   - Walk backwards in time
-    //
     let mut revwalk = repo.revwalk().context("could not create revwalk")?;
     revwalk
         .set_sorting(git2::Sort::TIME)
         .context("could not set revwalk sorting")?;
-    revwalk.push(base_oid).context("could not push base OID")?;
+    revwalk
+        .push(head_commit.id())
+        .context("could not push head OID")?;
 
     if let Some(mb) = merge_base {
         revwalk.hide(mb).context("could not hide merge-base")?;
     }
 
-    //
-    // ---- 6. Collect resulting commits ----
-    //
     let mut commits = Vec::new();
 
     for oid_res in revwalk {
@@ -320,6 +463,109 @@ pub fn get_commits_after_latest_tag<'repo>(
         commits.push(commit);
     }
 
+    Ok(commits)
+}
+
+/// Restricts the commits [`get_commits_after_latest_tag`] returns to those that actually touched a particular
+/// subset of the tree, e.g. `crates/foo/` in a monorepo.
+///
+/// A commit is kept when it changed at least one path matching `include` (or `include` is empty, meaning "don't
+/// restrict by inclusion") and that path isn't also covered by `exclude`. A commit whose only changes are to
+/// excluded paths is dropped even though it technically touched something.
+///
+/// The default (both empty) disables scoping entirely: every commit is kept, matching today's behavior.
+#[derive(Debug, Clone, Default)]
+pub struct PathScope {
+    pub include: Vec<glob::Pattern>,
+    pub exclude: Vec<glob::Pattern>,
+}
+
+impl PathScope {
+    fn is_active(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Diffs `commit`'s tree against its first parent (or an empty tree, for a root commit) and reports whether
+    /// any changed path is in scope. Restricting the diff itself to `include` via a `DiffOptions` pathspec keeps
+    /// this cheap on large trees, since libgit2 never has to materialize deltas for paths we don't care about.
+    fn matches(&self, repo: &git2::Repository, commit: &git2::Commit) -> Result<bool> {
+        let tree = commit.tree().context("could not get commit tree")?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree().context("could not get parent commit tree")?),
+            Err(_) => None,
+        };
+
+        let mut diff_opts = git2::DiffOptions::new();
+        for pattern in &self.include {
+            diff_opts.pathspec(pattern.as_str());
+        }
+
+        let diff = repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut diff_opts))
+            .with_context(|| format!("could not diff commit '{}' against its parent", commit.id()))?;
+
+        let mut in_scope = false;
+
+        for delta in diff.deltas() {
+            let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                continue;
+            };
+            let path = path.to_string_lossy();
+
+            if !self.exclude.iter().any(|pattern| pattern.matches(&path)) {
+                in_scope = true;
+                break;
+            }
+        }
+
+        Ok(in_scope)
+    }
+}
+
+/// Gather commits on the repository’s default branch that occurred *after the most recent SemVer tag*, using
+/// GitHub-style `<tag>...<branch>` comparison semantics.
+///
+/// This is just [`get_commits_between`] with `base` fixed to the latest SemVer tag and `head` fixed to the default
+/// branch; it's kept around because it's still the common case and callers shouldn't have to spell out the range
+/// by hand just to reproduce today's default behavior.
+///
+/// `scope` additionally restricts the result to commits that touched matching paths, so a monorepo can cut a
+/// release and changelog for just one sub-project while ignoring unrelated churn on the default branch.
+///
+/// `tag_selection` restricts and orders which tags count as "the latest" the same way, e.g. `pattern: "api-v*"` so
+/// that same monorepo only considers its own sub-project's tags rather than every tag in the repository.
+pub fn get_commits_after_latest_tag<'repo>(
+    repo: &'repo git2::Repository,
+    tag_selection: &TagSelection,
+    scope: &PathScope,
+) -> Result<(git2::Reference<'repo>, Vec<git2::Commit<'repo>>)> {
+    let (latest_ver, latest_tag_ref) = find_latest_semver_tag_matching(repo, tag_selection)?;
+    debug!("Latest semver tag chosen: {}", latest_ver);
+
+    let mut commits = get_commits_between(
+        repo,
+        GitReference::LatestSemverTag(tag_selection.clone()),
+        GitReference::DefaultBranch,
+    )
+    .context("could not collect commits between latest tag and default branch")?;
+
+    if scope.is_active() {
+        let before = commits.len();
+        commits = commits
+            .into_iter()
+            .map(|commit| scope.matches(repo, &commit).map(|matches| (commit, matches)))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter_map(|(commit, matches)| matches.then_some(commit))
+            .collect();
+
+        debug!(
+            "Path scope narrowed {} commits down to {}",
+            before,
+            commits.len()
+        );
+    }
+
     debug!(
         "Collected {} commits since latest tag {}",
         commits.len(),
@@ -329,6 +575,194 @@ pub fn get_commits_after_latest_tag<'repo>(
     Ok((latest_tag_ref, commits))
 }
 
+/// The outcome of comparing the local default branch against the latest SemVer tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseStatus {
+    /// The default branch *is* the latest tag; nothing new has landed.
+    UpToDate,
+
+    /// The default branch is `commits` ahead of the latest tag.
+    Ahead { commits: usize },
+
+    /// No SemVer tags exist yet, so there's nothing to compare against.
+    NoTags,
+}
+
+impl std::fmt::Display for ReleaseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseStatus::UpToDate => write!(f, "up to date; no release needed"),
+            ReleaseStatus::Ahead { commits } => write!(
+                f,
+                "{commits} commit{} ahead of the latest tag; a release is needed",
+                if *commits == 1 { "" } else { "s" }
+            ),
+            ReleaseStatus::NoTags => write!(f, "no SemVer tags found yet; nothing to compare against"),
+        }
+    }
+}
+
+/// Fast-path "is a new release needed" check: compares the local default branch against the commit the latest
+/// SemVer tag points to, without parsing commit messages or building a changelog. Commits are only walked in the
+/// `Ahead` case, to count them. Lets CI short-circuit before doing any of that work when nothing has landed since
+/// the last tag.
+///
+/// When an `origin` remote is reachable, this also compares the local default branch against its advertised tip
+/// and logs a heads-up if they differ — the local clone may be stale (e.g. `git fetch` hasn't run recently) — but
+/// that's only ever a debug note; it never changes the verdict, since staleness isn't this function's concern to
+/// fix.
+pub fn release_needed(repo: &git2::Repository) -> Result<ReleaseStatus> {
+    let (base_name, base_oid) = resolve_default_base(repo)?;
+
+    warn_if_remote_default_branch_diverges(repo, base_oid);
+
+    let Ok((_, tag_ref)) = find_latest_semver_tag(repo) else {
+        debug!("No semver tags found; treating as NoTags");
+        return Ok(ReleaseStatus::NoTags);
+    };
+
+    let tag_commit = tag_ref
+        .peel_to_commit()
+        .with_context(|| format!("could not peel tag '{}'", tag_ref.name().unwrap_or("?")))?;
+
+    if tag_commit.id() == base_oid {
+        debug!("Default branch '{}' is already at the latest tag", base_name);
+        return Ok(ReleaseStatus::UpToDate);
+    }
+
+    let commits = get_commits_between(
+        repo,
+        GitReference::LatestSemverTag(TagSelection::default()),
+        GitReference::DefaultBranch,
+    )
+    .with_context(|| format!("could not count commits ahead of latest tag on '{base_name}'"))?;
+
+    Ok(ReleaseStatus::Ahead {
+        commits: commits.len(),
+    })
+}
+
+/// Best-effort comparison of the local default branch against `origin`'s advertised default-branch tip. Any
+/// failure along the way (no remote, can't connect, remote doesn't advertise one) is swallowed; this is purely
+/// informational.
+fn warn_if_remote_default_branch_diverges(repo: &git2::Repository, local_oid: git2::Oid) {
+    let Some(remote_oid) = remote_default_branch_tip(repo) else {
+        return;
+    };
+
+    if remote_oid != local_oid {
+        debug!(
+            "Local default branch ({}) differs from origin's advertised tip ({}); \
+            `release_needed` may be evaluating a stale clone",
+            get_abbreviated_hash(local_oid),
+            get_abbreviated_hash(remote_oid)
+        );
+    }
+}
+
+fn remote_default_branch_tip(repo: &git2::Repository) -> Option<git2::Oid> {
+    let mut remote = repo.find_remote("origin").ok()?;
+    remote.connect(git2::Direction::Fetch).ok()?;
+
+    let default_branch = remote.default_branch().ok()?;
+    let default_branch = default_branch.as_str()?;
+
+    remote
+        .list()
+        .ok()?
+        .iter()
+        .find(|head| head.name() == default_branch)
+        .map(|head| head.oid())
+}
+
+/// Parses the SemVer version out of a tag reference's shorthand (e.g. "v1.2.3" or "1.2.3") by stripping a leading
+/// `v`, then parsing. A `TagSelection::strip_prefix` is not applied here, so a prefixed tag such as `api-v1.2.3`
+/// fails to parse even though `get_commits_after_latest_tag` can select it.
+pub fn parse_tag_version(tag_ref: &git2::Reference) -> Result<semver::Version> {
+    let name = tag_ref
+        .shorthand()
+        .ok_or_else(|| anyhow!(err!("Tag reference has no shorthand name")))?;
+
+    let semver_str = name.strip_prefix('v').unwrap_or(name);
+
+    semver::Version::parse(semver_str)
+        .with_context(|| format!("Could not parse tag '{name}' as SemVer"))
+}
+
+/// The result of describing `HEAD` relative to the nearest reachable tag, in the same shape `git describe` prints:
+/// `<tag>-<distance>-g<hash>` (or just `<tag>` when `distance` is `0`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescribeInfo {
+    /// Shorthand of the nearest reachable tag (lightweight or annotated).
+    pub tag: String,
+
+    /// Number of commits between the tag and `HEAD`. `0` means `HEAD` *is* the tag.
+    pub distance: usize,
+
+    /// `HEAD`'s abbreviated hash, via [`get_abbreviated_hash`].
+    pub abbreviated_hash: String,
+}
+
+impl std::fmt::Display for DescribeInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.distance == 0 {
+            write!(f, "{}", self.tag)
+        } else {
+            write!(f, "{}-{}-g{}", self.tag, self.distance, self.abbreviated_hash)
+        }
+    }
+}
+
+/// Describes `HEAD` against the nearest reachable tag, giving a quick "how far past the last release are we"
+/// signal without walking the full commit list the way [`get_commits_after_latest_tag`] does.
+///
+/// `describe_tags()` is set so both lightweight and annotated tags are considered (plain `git describe` ignores
+/// lightweight tags unless you pass `--tags`, which is the "peel logic" distinction this helper is meant to get
+/// right). If `HEAD` has no reachable tag at all, we fall back to describing from any ref (`describe_all()`) so
+/// this still returns something useful instead of erroring out.
+pub fn describe_head(repo: &git2::Repository) -> Result<DescribeInfo> {
+    let mut opts = git2::DescribeOptions::new();
+    opts.describe_tags();
+
+    let describe = repo.describe(&opts).or_else(|_| {
+        debug!("HEAD has no reachable tag; falling back to describing from any ref");
+        let mut opts = git2::DescribeOptions::new();
+        opts.describe_all();
+        repo.describe(&opts)
+    })?;
+
+    let mut format_opts = git2::DescribeFormatOptions::new();
+    format_opts.always_use_long_format(true);
+
+    let formatted = describe
+        .format(Some(&format_opts))
+        .context("could not format `git describe` result")?;
+
+    // `always_use_long_format` guarantees the `-<distance>-g<hash>` suffix is always present, so we can reliably
+    // split it off the end regardless of what the tag itself is named (even one containing dashes).
+    let (tag, distance) = formatted
+        .rsplit_once("-g")
+        .and_then(|(rest, _)| rest.rsplit_once('-'))
+        .map(|(tag, distance)| (tag, distance))
+        .ok_or_else(|| anyhow!(err!("unexpected `git describe` output '{formatted}'")))?;
+
+    let distance: usize = distance
+        .parse()
+        .with_context(|| format!("could not parse commit distance from `git describe` output '{formatted}'"))?;
+
+    let head_oid = repo
+        .head()
+        .context("repository has no HEAD")?
+        .target()
+        .context("HEAD has no target")?;
+
+    Ok(DescribeInfo {
+        tag: tag.to_string(),
+        distance,
+        abbreviated_hash: get_abbreviated_hash(head_oid),
+    })
+}
+
 pub fn get_short_message(commit: &git2::Commit) -> String {
     let full_message = commit.message().unwrap_or_default();
     if let Some(pos) = full_message.find('\n') {
@@ -385,6 +819,10 @@ mod tests {
         let workdir = repo.workdir().expect("repo must have a workdir");
         let full_path = workdir.join(path.as_ref());
 
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+
         fs::write(&full_path, contents).unwrap();
 
         let mut index = repo.index().unwrap();
@@ -417,9 +855,11 @@ mod tests {
     fn parses_git_ssh_github_url() {
         let repo = init_repo_with_remote("git@github.com:my-org/my-repo.git", "ssh");
 
-        let (org, name) = get_org_and_repo(&repo).unwrap();
-        assert_eq!(org, "my-org");
-        assert_eq!(name, "my-repo");
+        let remote = parse_remote(&repo).unwrap();
+        assert_eq!(remote.host, "github.com");
+        assert_eq!(remote.owner, "my-org");
+        assert_eq!(remote.name, "my-repo");
+        assert_eq!(remote.provider, Provider::GitHub);
     }
 
     #[test]
@@ -427,9 +867,11 @@ mod tests {
         let repo =
             init_repo_with_remote("https://github.com/another-org/awesome-repo.git", "https");
 
-        let (org, name) = get_org_and_repo(&repo).unwrap();
-        assert_eq!(org, "another-org");
-        assert_eq!(name, "awesome-repo");
+        let remote = parse_remote(&repo).unwrap();
+        assert_eq!(remote.host, "github.com");
+        assert_eq!(remote.owner, "another-org");
+        assert_eq!(remote.name, "awesome-repo");
+        assert_eq!(remote.provider, Provider::GitHub);
     }
 
     #[test]
@@ -439,9 +881,36 @@ mod tests {
             "ssh_scheme",
         );
 
-        let (org, name) = get_org_and_repo(&repo).unwrap();
-        assert_eq!(org, "yet-another-org");
-        assert_eq!(name, "cool-repo");
+        let remote = parse_remote(&repo).unwrap();
+        assert_eq!(remote.host, "github.com");
+        assert_eq!(remote.owner, "yet-another-org");
+        assert_eq!(remote.name, "cool-repo");
+        assert_eq!(remote.provider, Provider::GitHub);
+    }
+
+    #[test]
+    fn parses_self_hosted_gitlab_url() {
+        let repo = init_repo_with_remote(
+            "https://gitlab.mycompany.com/some-team/some-repo.git",
+            "gitlab_self_hosted",
+        );
+
+        let remote = parse_remote(&repo).unwrap();
+        assert_eq!(remote.host, "gitlab.mycompany.com");
+        assert_eq!(remote.owner, "some-team");
+        assert_eq!(remote.name, "some-repo");
+        assert_eq!(remote.provider, Provider::GitLab);
+    }
+
+    #[test]
+    fn unrecognized_host_comes_back_unknown() {
+        let repo = init_repo_with_remote(
+            "https://git.mycompany.com/some-team/some-repo.git",
+            "unknown_host",
+        );
+
+        let remote = parse_remote(&repo).unwrap();
+        assert_eq!(remote.provider, Provider::Unknown);
     }
 
     #[test]
@@ -454,7 +923,7 @@ mod tests {
 
         let repo = Repository::init(&path).unwrap();
 
-        let err = get_org_and_repo(&repo).unwrap_err();
+        let err = parse_remote(&repo).unwrap_err();
         let msg = format!("{err:#}");
         assert!(
             msg.contains("Could not find remote 'origin'"),
@@ -462,6 +931,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tag_pattern_restricts_selection_for_monorepos() {
+        let repo = init_repo("tag_pattern_monorepo");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("api-v1.2.3", c1.as_object(), &sig, "api tag", false)
+            .unwrap();
+
+        let c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+        repo.tag("web-v9.0.0", c2.as_object(), &sig, "web tag", false)
+            .unwrap();
+
+        let selection = TagSelection {
+            pattern: Some(glob::Pattern::new("api-v*").unwrap()),
+            strip_prefix: Some("api-v".to_string()),
+            skip_prereleases: false,
+        };
+
+        let (ver, tag_ref) = find_latest_semver_tag_matching(&repo, &selection).unwrap();
+
+        assert_eq!(ver, semver::Version::new(1, 2, 3));
+        assert_eq!(tag_ref.shorthand(), Some("api-v1.2.3"));
+    }
+
+    #[test]
+    fn skip_prereleases_excludes_release_candidates() {
+        let repo = init_repo("tag_skip_prereleases");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+        repo.tag("2.0.0-rc.1", c2.as_object(), &sig, "rc tag", false)
+            .unwrap();
+
+        let selection = TagSelection {
+            skip_prereleases: true,
+            ..Default::default()
+        };
+
+        let (ver, _) = find_latest_semver_tag_matching(&repo, &selection).unwrap();
+        assert_eq!(ver, semver::Version::new(1, 0, 0));
+
+        // Without the flag, the prerelease wins since it's numerically higher.
+        let (ver, _) = find_latest_semver_tag_matching(&repo, &TagSelection::default()).unwrap();
+        assert_eq!(ver, semver::Version::parse("2.0.0-rc.1").unwrap());
+    }
+
     #[test]
     fn returns_commits_after_latest_semver_tag() {
         let repo = init_repo("with_tags");
@@ -485,7 +1005,8 @@ mod tests {
         let c3 = commit_file(&repo, "file.txt", "three", "commit 3");
 
         // New API: GitHub-style compare <latest tag>...<default branch>
-        let (latest_tag_ref, commits_after) = get_commits_after_latest_tag(&repo).unwrap();
+        let (latest_tag_ref, commits_after) =
+            get_commits_after_latest_tag(&repo, &TagSelection::default(), &PathScope::default()).unwrap();
 
         assert_eq!(latest_tag_ref.shorthand(), Some("1.1.0"));
 
@@ -494,6 +1015,115 @@ mod tests {
         assert_eq!(commits_after[0].id(), c3.id());
     }
 
+    #[test]
+    fn path_scope_keeps_only_commits_touching_included_paths() {
+        let repo = init_repo("path_scope_include");
+
+        let c1 = commit_file(&repo, "crates/foo/lib.rs", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let c2 = commit_file(&repo, "crates/foo/lib.rs", "two", "touches foo");
+        let _c3 = commit_file(&repo, "crates/bar/lib.rs", "three", "touches bar");
+
+        let scope = PathScope {
+            include: vec![glob::Pattern::new("crates/foo/**").unwrap()],
+            exclude: vec![],
+        };
+
+        let (_, commits) =
+            get_commits_after_latest_tag(&repo, &TagSelection::default(), &scope).unwrap();
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), c2.id());
+    }
+
+    #[test]
+    fn path_scope_drops_commits_that_only_touch_excluded_paths() {
+        let repo = init_repo("path_scope_exclude");
+
+        let c1 = commit_file(&repo, "crates/foo/lib.rs", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let _c2 = commit_file(&repo, "crates/foo/README.md", "two", "docs only");
+        let c3 = commit_file(&repo, "crates/foo/lib.rs", "three", "real change");
+
+        let scope = PathScope {
+            include: vec![glob::Pattern::new("crates/foo/**").unwrap()],
+            exclude: vec![glob::Pattern::new("crates/foo/README.md").unwrap()],
+        };
+
+        let (_, commits) =
+            get_commits_after_latest_tag(&repo, &TagSelection::default(), &scope).unwrap();
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), c3.id());
+    }
+
+    #[test]
+    fn get_commits_between_matches_explicit_tag_and_branch() {
+        let repo = init_repo("commits_between_explicit");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+
+        // Whatever git's `init.defaultBranch` is configured to (commonly "master" or "main").
+        let branch_name = repo.head().unwrap().shorthand().unwrap().to_string();
+
+        let commits = get_commits_between(
+            &repo,
+            GitReference::Tag("1.0.0".to_string()),
+            GitReference::Branch(branch_name),
+        )
+        .unwrap();
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), c2.id());
+    }
+
+    #[test]
+    fn named_ref_falls_back_from_tag_to_branch() {
+        let repo = init_repo("commits_between_named_ref");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let _c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+
+        // The branch name isn't a tag, so NamedRef should fall through to refs/heads/<branch>.
+        let branch_name = repo.head().unwrap().shorthand().unwrap().to_string();
+
+        let commits = get_commits_between(
+            &repo,
+            GitReference::FullCommit(c1.id()),
+            GitReference::NamedRef(branch_name),
+        )
+        .unwrap();
+
+        assert_eq!(commits.len(), 1);
+    }
+
+    #[test]
+    fn named_ref_errors_when_nothing_matches() {
+        let repo = init_repo("commits_between_unresolvable");
+        let _c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+
+        let err = get_commits_between(
+            &repo,
+            GitReference::NamedRef("does-not-exist".to_string()),
+            GitReference::DefaultBranch,
+        )
+        .unwrap_err();
+
+        assert!(format!("{err:#}").contains("could not resolve reference"));
+    }
+
     #[test]
     fn errors_when_no_tags() {
         let repo = init_repo("no_tags");
@@ -503,7 +1133,7 @@ mod tests {
         let _c2 = commit_file(&repo, "file.txt", "two", "commit 2");
 
         // New API returns an error when no SemVer tags are present
-        let res = get_commits_after_latest_tag(&repo);
+        let res = get_commits_after_latest_tag(&repo, &TagSelection::default(), &PathScope::default());
 
         assert!(res.is_err());
         let msg = res.err().unwrap().to_string();
@@ -513,6 +1143,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn release_needed_reports_no_tags() {
+        let repo = init_repo("release_needed_no_tags");
+        let _c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+
+        assert_eq!(release_needed(&repo).unwrap(), ReleaseStatus::NoTags);
+    }
+
+    #[test]
+    fn release_needed_reports_up_to_date() {
+        let repo = init_repo("release_needed_up_to_date");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        assert_eq!(release_needed(&repo).unwrap(), ReleaseStatus::UpToDate);
+    }
+
+    #[test]
+    fn release_needed_reports_ahead_with_commit_count() {
+        let repo = init_repo("release_needed_ahead");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let _c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+        let _c3 = commit_file(&repo, "file.txt", "three", "commit 3");
+
+        assert_eq!(
+            release_needed(&repo).unwrap(),
+            ReleaseStatus::Ahead { commits: 2 }
+        );
+    }
+
     #[test]
     fn get_short_message_truncates_at_first_newline() {
         let repo = init_repo("short_message_truncate");
@@ -547,6 +1215,66 @@ mod tests {
         assert_eq!(short, "");
     }
 
+    #[test]
+    fn describe_reports_tag_when_head_is_on_it() {
+        let repo = init_repo("describe_on_tag");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let info = describe_head(&repo).unwrap();
+
+        assert_eq!(info.tag, "1.0.0");
+        assert_eq!(info.distance, 0);
+        assert_eq!(info.to_string(), "1.0.0");
+    }
+
+    #[test]
+    fn describe_reports_distance_past_latest_tag() {
+        let repo = init_repo("describe_past_tag");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.tag("1.0.0", c1.as_object(), &sig, "tag 1.0.0", false)
+            .unwrap();
+
+        let _c2 = commit_file(&repo, "file.txt", "two", "commit 2");
+        let c3 = commit_file(&repo, "file.txt", "three", "commit 3");
+
+        let info = describe_head(&repo).unwrap();
+
+        assert_eq!(info.tag, "1.0.0");
+        assert_eq!(info.distance, 2);
+        assert_eq!(info.abbreviated_hash, get_abbreviated_hash(c3.id()));
+        assert_eq!(info.to_string(), format!("1.0.0-2-g{}", info.abbreviated_hash));
+    }
+
+    #[test]
+    fn describe_considers_lightweight_tags() {
+        let repo = init_repo("describe_lightweight");
+
+        let c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+        // `force: false`, and no signature/message -> a lightweight tag, not annotated.
+        repo.tag_lightweight("1.2.3", c1.as_object(), false).unwrap();
+
+        let info = describe_head(&repo).unwrap();
+
+        assert_eq!(info.tag, "1.2.3");
+        assert_eq!(info.distance, 0);
+    }
+
+    #[test]
+    fn describe_falls_back_to_any_ref_without_tags() {
+        let repo = init_repo("describe_no_tags");
+        let _c1 = commit_file(&repo, "file.txt", "one", "commit 1");
+
+        // No tags exist at all, so this should still succeed via the `describe_all()` fallback rather than erroring.
+        let info = describe_head(&repo).unwrap();
+        assert_eq!(info.distance, 0);
+    }
+
     #[test]
     fn get_abbreviated_hash_truncates_to_7_chars() {
         // Construct a known Oid from a full 40-char hex string
@@ -0,0 +1,63 @@
+use fd_lock::{RwLock, RwLockWriteGuard};
+use rootcause::prelude::*;
+use std::{fs::File, io::Write, path::PathBuf};
+
+/// An advisory lock scoped to a single repository, held for as long as this value is alive. Dropping it releases
+/// the underlying `flock`, which covers both the normal exit path and any `?`-propagated error path, since
+/// dropping `Cli` drops this along with it.
+pub struct ReleaseLock {
+    _guard: RwLockWriteGuard<'static, File>,
+}
+
+fn lock_file_path(repo: &git2::Repository) -> PathBuf {
+    repo.path().join("release.lock")
+}
+
+/// Acquires a non-blocking, advisory lock scoped to `repo` so that two concurrent `release` invocations against
+/// the same repository can't clobber each other's intermediate changelog files.
+///
+/// This is the same build-lock pattern tools like the rustc bootstrap use to serialize conflicting runs: take a
+/// `try_write()` on an `fd_lock::RwLock`, and bail loudly instead of blocking if someone else already holds it.
+pub fn acquire(repo: &git2::Repository) -> Result<ReleaseLock, Report> {
+    let path = lock_file_path(repo);
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(&path)
+        .context(format!("Could not open lock file at {:#?}", path))?;
+
+    // Leaked intentionally: the guard we take below needs the `RwLock` to outlive it, and we want this lock held
+    // for the entire lifetime of the process, not just this function. Promoting it to `'static` via `Box::leak`
+    // avoids a self-referential struct for what is, in practice, a single lock taken once at startup.
+    let lock: &'static mut RwLock<File> = Box::leak(Box::new(RwLock::new(
+        file.try_clone().context("Could not duplicate lock file handle")?,
+    )));
+
+    let guard = lock.try_write().map_err(|_| {
+        let holder = std::fs::read_to_string(&path).unwrap_or_default();
+        let holder = holder.trim();
+
+        if holder.is_empty() {
+            report!(
+                "A release is already running against this repository (lock held at {:#?}); \
+                wait for it to finish or remove the lock file if it's stale",
+                path
+            )
+        } else {
+            report!(
+                "A release is already running against this repository (pid {holder}, lock held at {:#?}); \
+                wait for it to finish or remove the lock file if it's stale",
+                path
+            )
+        }
+    })?;
+
+    // Best-effort: record our pid so a concurrent run can name us in its own error message.
+    let _ = file.set_len(0);
+    let _ = write!(file, "{}", std::process::id());
+
+    Ok(ReleaseLock { _guard: guard })
+}
@@ -1,18 +1,28 @@
+mod alias;
 mod changelog;
 mod conf;
+mod conventional;
 mod error;
+mod forge;
 mod git;
 mod llm;
+mod lock;
+mod publish;
+pub(crate) mod suggest;
 
 use crate::cli::conf::{CliConfig, Configuration};
-use bytes::Bytes;
 use clap::{Parser, ValueEnum};
 use colored::Colorize;
-use octocrab::Octocrab;
+use indexmap::IndexMap;
 use polyfmt::{debug, finish, pause, print, println, question, resume, spacer, success, warning};
 use rootcause::prelude::*;
 use serde::{Deserialize, Serialize, de};
-use std::{collections::HashMap, fmt::Debug, io::Write, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    io::{IsTerminal, Write},
+    path::PathBuf,
+};
 use strum_macros::{EnumString, VariantNames};
 
 const RELEASE_DETAILS_TEMPLATE: &str = r#"
@@ -21,6 +31,12 @@ Final Release Details:
 {{ divider }} Repository:   {{ repository }}
 {{ divider }} Version:      {{ semver }}
 {{ divider }} Release Date: {{ date }}
+{%- if prerelease %}
+{{ divider }} Prerelease:   yes
+{%- endif -%}
+{%- if draft %}
+{{ divider }} Draft:        yes
+{%- endif %}
 {{ divider }} Changelog:    {{ changelog_path }}
 {%- if assets | length > 0 %}
 {{ divider }} Assets:
@@ -28,6 +44,12 @@ Final Release Details:
 {{ divider }}  • {{ name }}: {{ path }}
 {%- endfor -%}
 {%- endif -%}
+{%- if publishers | length > 0 %}
+{{ divider }} Publishing:
+{%- for publisher in publishers %}
+{{ divider }}  • {{ publisher.ecosystem }}: {{ publisher.targets | join(sep=", ") }}
+{%- endfor -%}
+{%- endif -%}
 "#;
 
 #[derive(Default, Debug, Clone, ValueEnum, Serialize, PartialEq, Eq, EnumString, VariantNames)]
@@ -61,6 +83,39 @@ impl From<OutputFormat> for polyfmt::Format {
     }
 }
 
+#[derive(Default, Debug, Clone, ValueEnum, Serialize, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(ascii_case_insensitive)]
+#[serde(try_from = "String")]
+pub(crate) enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+fn deserialize_color_choice<'de, D>(deserializer: D) -> Result<ColorChoice, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+
+    ColorChoice::from_str(&s, true).map_err(de::Error::custom)
+}
+
+impl ColorChoice {
+    /// Resolves the choice down to a single yes/no, checking whether stderr is a TTY and whether `NO_COLOR` is set
+    /// for the `Auto` case.
+    fn resolve(&self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+        }
+    }
+}
+
 /// Release — Helps with Github releases, changelogs, asset uploading.
 #[derive(Debug, Parser, Clone)]
 #[command(name = "release")]
@@ -71,7 +126,11 @@ impl From<OutputFormat> for polyfmt::Format {
 // separate locations then resolving mentally would be come difficult.
 pub(crate) struct Args {
     /// Version to release (SemVer), e.g. 1.4.2
-    semver: String,
+    ///
+    /// If omitted, the next version is inferred from Conventional Commit messages since the last tag (a breaking
+    /// change bumps major, a `feat` bumps minor, a recognized fix/maintenance type bumps patch). Not used at all
+    /// when `--from-context` is given, since the version is already baked into the exported context.
+    semver: Option<String>,
 
     /// Asset(s) to attach to the release (repeatable)
     #[arg(short, long = "asset")]
@@ -99,18 +158,73 @@ pub(crate) struct Args {
     #[arg(short, long, value_enum)]
     output_format: Option<OutputFormat>,
 
+    /// Controls whether ANSI colors are used in output.
+    ///
+    /// `auto` colors only when stderr is a terminal and `NO_COLOR` is unset.
+    #[arg(long, value_enum)]
+    color: Option<ColorChoice>,
+
     /// An alternate location to find the configuration file.
     ///
     /// By default the config file is searched for at `release.toml` and `.config/release.toml`
     #[arg(short, long)]
     config_file_path: Option<PathBuf>,
+
+    /// Instead of creating the release, print the collected release context as JSON and exit. The resulting
+    /// document can later be fed back in with `--from-context` to render templates without touching git at all.
+    #[arg(long)]
+    context: bool,
+
+    /// Render from a previously exported `--context` JSON document instead of collecting commits from git.
+    ///
+    /// Useful in CI where the machine doing the publish doesn't have the git history available.
+    #[arg(long)]
+    from_context: Option<PathBuf>,
+
+    /// Checks whether a release is needed (any commits landed on the default branch since the last SemVer tag),
+    /// prints the verdict, and exits, without building a changelog or contacting any forge. Commits are only walked
+    /// to count them when the default branch is ahead of the tag.
+    #[arg(long)]
+    check: bool,
+
+    /// Prints `git describe`-style output for HEAD (nearest tag, distance, abbreviated hash) and exits, without
+    /// building a changelog or contacting any forge.
+    #[arg(long)]
+    describe: bool,
+
+    /// Base git reference to compare from (a tag, branch, full commit hash, or other ref name), e.g. `v1.2.0`.
+    ///
+    /// Overrides the default of "the latest SemVer tag", letting you build a changelog for an arbitrary range, e.g.
+    /// `v1.2.0...release/2.0`. Requires `--head`. Since there's no tag to infer a previous version from in this
+    /// mode, the version (the `SEMVER` positional argument) must also be given explicitly.
+    #[arg(long, requires = "head")]
+    base: Option<String>,
+
+    /// Head git reference to compare to (a tag, branch, full commit hash, or other ref name), e.g. `release/2.0`.
+    ///
+    /// Overrides the default of "the resolved default branch". Requires `--base`.
+    #[arg(long, requires = "base")]
+    head: Option<String>,
+
+    /// Force the forge release's prerelease flag, overriding the value inferred from the SemVer prerelease
+    /// identifier (e.g. `1.4.0-rc.1` is detected as a prerelease automatically).
+    #[arg(long)]
+    pub prerelease: Option<bool>,
+
+    /// Create the forge release as a draft (unpublished, visible only to maintainers) release.
+    #[arg(long)]
+    pub draft: bool,
 }
 
-#[derive(Debug, Clone)]
 pub struct Cli {
     args: Args,
     conf: CliConfig,
     release: Release,
+
+    /// Held for the lifetime of the `Cli`, so the advisory lock is released whenever this value is dropped,
+    /// whether we exit normally or an error path returns early. `None` when rendering from `--from-context`, since
+    /// that path never opens the repo, so there's nothing to lock.
+    _lock: Option<lock::ReleaseLock>,
 }
 
 // So we never forget to call [`polyfmt::Formatter::finish`]
@@ -134,9 +248,18 @@ impl TryFrom<String> for Llm {
         match value.to_ascii_lowercase().as_str() {
             "gemini" => Ok(Self::Gemini),
             "openai" => Ok(Self::OpenAI),
-            _ => Err(report!(
-                "Could not parse LLM vendor into any accepted values (got `{value}`)"
-            )),
+            _ => {
+                let hint = suggest::did_you_mean(&value, ["gemini", "openai"]);
+
+                match hint {
+                    Some(hint) => Err(report!(
+                        "Could not parse LLM vendor into any accepted values (got `{value}`); {hint}"
+                    )),
+                    None => Err(report!(
+                        "Could not parse LLM vendor into any accepted values (got `{value}`)"
+                    )),
+                }
+            }
         }
     }
 }
@@ -164,13 +287,30 @@ impl From<Llm> for ::llm::builder::LLMBackend {
 
 impl Cli {
     pub fn new() -> Result<Self, Report> {
-        let args = Args::parse();
+        let raw_args: Vec<String> = std::env::args().collect();
+
+        // Aliases need to be resolved against the raw argv before clap ever sees it, so we do a lightweight config
+        // load first just to get at the `[aliases]` table. `--config-file-path` can't be parsed by clap yet at
+        // this point, so we scan for it by hand here and feed it through, same as the full, flag-aware load below
+        // in `resolve_config`; otherwise a user with multiple config files relying on that flag to disambiguate
+        // would hit the ambiguity error below before ever reaching clap.
+        let early_config_file_path = find_config_file_path_override(&raw_args[1..]);
+
+        let early_conf = Configuration::<CliConfig>::load(early_config_file_path)
+            .context("Could not load configuration while resolving aliases")?;
+
+        let expanded_args = alias::expand_aliases(raw_args[1..].to_vec(), &early_conf.aliases)
+            .context("Could not expand command alias")?;
+
+        let args = Args::parse_from(std::iter::once(raw_args[0].clone()).chain(expanded_args));
 
         let conf = Cli::resolve_config(&args).context("Could not load configuration")?;
 
         let output_format = polyfmt::Format::from(conf.output_format.clone());
 
-        error::alter_error_formatter(conf.debug);
+        let color_enabled = conf.color.resolve();
+        colored::control::set_override(color_enabled);
+        error::alter_error_formatter(conf.debug, color_enabled);
 
         let fmtter_options = polyfmt::Options {
             debug: conf.debug,
@@ -182,13 +322,62 @@ impl Cli {
 
         polyfmt::set_global_formatter(fmtter);
 
-        let release =
-            get_release_info(&args.assets, &args.semver).context("Could not get release info")?;
+        let (release, _lock) = if let Some(context_path) = &args.from_context {
+            let release = load_release_context(context_path)
+                .context("Could not load release context")?;
+
+            (release, None)
+        } else {
+            let repo = match git2::Repository::open(".") {
+                Ok(repo) => repo,
+                Err(e) => bail!("failed to open local repo: {:#}", e),
+            };
+
+            // `--check` is a fast path meant to let CI decide whether a real release invocation is worth running
+            // at all, so it's handled here, before we acquire the release lock or walk commits to build a full
+            // `Release`.
+            if args.check {
+                let status =
+                    git::release_needed(&repo).context("Could not check whether a release is needed")?;
+
+                println!("{status}");
+                std::process::exit(0);
+            }
+
+            // Same idea as `--check`: a cheap signal without building a full `Release`.
+            if args.describe {
+                let description = git::describe_head(&repo).context("Could not describe HEAD")?;
+
+                println!("{description}");
+                std::process::exit(0);
+            }
+
+            // Acquired before we touch the changelog so two concurrent `release` invocations in the same repo
+            // can't clobber each other's intermediate files.
+            let lock = lock::acquire(&repo).context("Could not acquire release lock")?;
+
+            let release = get_release_info(
+                &repo,
+                &args.assets,
+                args.semver.as_deref(),
+                &conf.changelog.headings,
+                &conf.tags,
+                &conf.paths,
+                args.base.as_deref(),
+                args.head.as_deref(),
+                args.prerelease,
+                args.draft,
+            )
+            .context("Could not get release info")?;
+
+            (release, Some(lock))
+        };
 
         let cli = Cli {
             args,
             conf,
             release,
+            _lock,
         };
 
         Ok(cli)
@@ -228,19 +417,34 @@ impl Cli {
             conf.output_format = output_format.clone()
         }
 
+        if let Some(color) = &args.color {
+            conf.color = color.clone();
+        }
+
         conf.debug = args.debug;
 
+        validate_custom_templates(&conf).context("Could not validate custom templates")?;
+
         Ok(conf)
     }
 
     pub fn run(&mut self) -> Result<(), Report> {
-        print!("Creating release v{}", &self.args.semver; vec![polyfmt::Format::Spinner]);
+        if self.args.context {
+            let context = serde_json::to_string_pretty(&self.release)
+                .context("Could not serialize release context to JSON")?;
+
+            println!("{}", context);
+
+            return Ok(());
+        }
+
+        print!("Creating release v{}", &self.release.version; vec![polyfmt::Format::Spinner]);
 
         (self.release.changelog.0, self.release.changelog.1) = self
             .process_changelog()
             .context("Could not create changelog")?;
 
-        print!("Creating release v{}", &self.args.semver; vec![polyfmt::Format::Spinner]);
+        print!("Creating release v{}", &self.release.version; vec![polyfmt::Format::Spinner]);
 
         let release_details = self
             .render_release_details()
@@ -255,8 +459,8 @@ impl Cli {
             return Ok(());
         }
 
-        self.create_github_release()
-            .context("Could not create release on Github")?;
+        self.create_release()
+            .context("Could not create release")?;
 
         success!("Release successfully created!");
 
@@ -304,8 +508,15 @@ impl Cli {
 
         // If we're working with a new file first build the template then insert it into the new file.
 
+        let template = read_custom_template(
+            &self.conf.changelog.template,
+            &self.conf.changelog.template_path,
+        )
+        .context("Could not read custom changelog template")?
+        .unwrap_or_else(|| changelog::CHANGELOG_TEMPLATE.to_string());
+
         let mut tera = tera::Tera::default();
-        tera.add_raw_template("changelog_template", changelog::CHANGELOG_TEMPLATE)?;
+        tera.add_raw_template("changelog_template", &template)?;
 
         let mut context = tera::Context::new();
         context.insert("organization", &self.release.organization);
@@ -313,6 +524,7 @@ impl Cli {
         context.insert("repo", &self.release.repo);
         context.insert("version", &self.release.version);
         context.insert("short_commits", &self.release.short_commits);
+        context.insert("grouped_commits", &self.release.grouped_commits);
 
         let content = tera.render("changelog_template", &context)?;
 
@@ -352,12 +564,108 @@ impl Cli {
     }
 }
 
-fn get_release_info(assets: &Vec<PathBuf>, semver: &str) -> Result<Release, Report> {
-    let repo = match git2::Repository::open(".") {
-        Ok(repo) => repo,
-        Err(e) => bail!("failed to open local repo: {:#}", e),
+/// Hand-scans argv (excluding the binary name) for `--config-file-path`/`-c`, in either `--flag value` or
+/// `--flag=value` form, so the alias-resolving pre-load in [`Cli::new`] can honor the same override clap will use
+/// once it actually parses. Best-effort: clap's own parsing remains the source of truth for the real run.
+fn find_config_file_path_override(args: &[String]) -> Option<PathBuf> {
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if let Some(value) = arg.strip_prefix("--config-file-path=") {
+            return Some(PathBuf::from(value));
+        }
+
+        if let Some(value) = arg.strip_prefix("-c=") {
+            return Some(PathBuf::from(value));
+        }
+
+        if arg == "--config-file-path" || arg == "-c" {
+            return iter.next().map(PathBuf::from);
+        }
+    }
+
+    None
+}
+
+/// Checks that any custom changelog/release-details templates configured via `template`/`template_path` exist and
+/// parse, so a broken template fails at config-resolution time rather than mid-release.
+fn validate_custom_templates(conf: &CliConfig) -> Result<(), Report> {
+    validate_template(
+        "changelog",
+        &conf.changelog.template,
+        &conf.changelog.template_path,
+    )?;
+    validate_template(
+        "release_details",
+        &conf.release_details.template,
+        &conf.release_details.template_path,
+    )?;
+
+    Ok(())
+}
+
+fn validate_template(
+    name: &str,
+    inline: &Option<String>,
+    path: &Option<PathBuf>,
+) -> Result<(), Report> {
+    let Some(contents) = read_custom_template(inline, path)
+        .context(format!("Could not read custom `{name}` template"))?
+    else {
+        return Ok(());
     };
 
+    let mut tera = tera::Tera::default();
+    tera.add_raw_template(name, &contents)
+        .context(format!("Custom `{name}` template failed to parse"))?;
+
+    Ok(())
+}
+
+/// Reads a custom template's contents from its inline value or file path (inline wins if both are set), or `None`
+/// if neither is configured, in which case the caller should fall back to the built-in default template.
+fn read_custom_template(
+    inline: &Option<String>,
+    path: &Option<PathBuf>,
+) -> Result<Option<String>, Report> {
+    if let Some(inline) = inline {
+        return Ok(Some(inline.clone()));
+    }
+
+    if let Some(path) = path {
+        let contents = std::fs::read_to_string(path)
+            .context(format!("Could not read template file at {:#?}", path))?;
+
+        return Ok(Some(contents));
+    }
+
+    Ok(None)
+}
+
+/// Reads back a `Release` previously exported with `--context`, so templates can be rendered and a release created
+/// without ever opening a git repository.
+fn load_release_context(path: &std::path::Path) -> Result<Release, Report> {
+    let contents = std::fs::read_to_string(path)
+        .context(format!("Could not read release context from {:#?}", path))?;
+
+    serde_json::from_str(&contents).context(format!(
+        "Could not parse release context in {:#?} as JSON",
+        path
+    ))
+}
+
+fn get_release_info(
+    repo: &git2::Repository,
+    assets: &Vec<PathBuf>,
+    semver: Option<&str>,
+    changelog_headings: &HashMap<String, String>,
+    tags: &conf::Tags,
+    paths: &conf::Paths,
+    base: Option<&str>,
+    head: Option<&str>,
+    prerelease_override: Option<bool>,
+    draft: bool,
+) -> Result<Release, Report> {
     // Process assets so they have names and paths.
     let mut parsed_assets = vec![];
     for asset_path in assets {
@@ -377,18 +685,33 @@ fn get_release_info(assets: &Vec<PathBuf>, semver: &str) -> Result<Release, Repo
         });
     }
 
-    let release = Release::new(&repo, semver, parsed_assets).context("Could not create release")?;
+    let release = Release::new(
+        repo,
+        semver,
+        parsed_assets,
+        changelog_headings,
+        tags,
+        paths,
+        base,
+        head,
+        prerelease_override,
+        draft,
+    )
+    .context("Could not create release")?;
 
     Ok(release)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Asset {
     pub name: String,
     pub path: PathBuf,
 }
 
-#[derive(Debug, Clone)]
+/// Everything collected from git/config for a release. Serializable so it can be exported with `--context` and
+/// read back with `--from-context`, decoupling commit collection (which needs a real git checkout) from rendering
+/// (which doesn't).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Release {
     /// The organization for the current repo. In 'clintjedwards/gofer' it would be 'clintjedwards'.
     pub organization: String,
@@ -396,23 +719,41 @@ pub struct Release {
     /// The repo name for the current repo. In 'clintjedwards/gofer' it would be 'gofer'.
     pub repo: String,
 
+    /// The full parsed `origin` remote (host, owner, name, and inferred forge provider), used to select which
+    /// `Forge` backend handles this release.
+    pub remote: git::Remote,
+
     /// The SEMVER version formatted as <Major>.<Minor>.<Path>. ex: 0.9.1
     pub version: String,
 
+    /// Whether the forge release should be marked as a prerelease, so it's never advertised as "latest" on the
+    /// releases page. Auto-detected from a non-empty SemVer prerelease identifier (e.g. `1.4.0-rc.1`), unless
+    /// overridden with `--prerelease`.
+    pub prerelease: bool,
+
+    /// Whether the forge release should be created as a draft. Never auto-detected; only set via `--draft`.
+    pub draft: bool,
+
     /// The current date formatted as <Month> <Date>, <Year>. ex: January 26, 2025.
     pub date: String,
 
     /// The path and contents of the changelog.
     pub changelog: (PathBuf, String),
 
-    /// Short commit hashes and their short descriptions. This is included in the changelog template so that users
-    /// correctly understand which range of commits is being used here.
-    pub short_commits: HashMap<String, String>,
+    /// Short commit hashes and their short descriptions, in commit order. This is included in the changelog
+    /// template so that users correctly understand which range of commits is being used here. An `IndexMap` rather
+    /// than a `HashMap` so `--context` exports are stable, diffable JSON instead of reordering on every run.
+    pub short_commits: IndexMap<String, String>,
 
     /// The same corpus as `short_commits` but with the full long commit descriptions. This is not included in the
     /// template but instead given to LLM, should the user choose to use one. This helps the LLM create better
     /// descriptions according to the template provided.k
-    pub full_commits: HashMap<String, String>,
+    pub full_commits: IndexMap<String, String>,
+
+    /// Commits parsed against the Conventional Commit grammar and bucketed into changelog sections (Features, Bug
+    /// Fixes, Performance, Breaking Changes, Other), keyed by the section's rendered heading. Iterated by
+    /// `CHANGELOG_TEMPLATE` in section order; a commit that doesn't match the grammar still shows up under "Other".
+    pub grouped_commits: IndexMap<String, Vec<conventional::CommitEntry>>,
 
     /// The name and path to all the assets included in the release.
     pub assets: Vec<Asset>,
@@ -421,22 +762,95 @@ pub struct Release {
 impl Release {
     pub fn new(
         repository: &git2::Repository,
-        version: &str,
+        version: Option<&str>,
         assets: Vec<Asset>,
+        changelog_headings: &HashMap<String, String>,
+        tags: &conf::Tags,
+        paths: &conf::Paths,
+        base: Option<&str>,
+        head: Option<&str>,
+        prerelease_override: Option<bool>,
+        draft: bool,
     ) -> Result<Self, Report> {
-        semver::Version::parse(version).context(format!(
+        let remote = git::parse_remote(repository)
+            .context("Could not determine the forge remote from git")?;
+
+        let tag_selection = git::TagSelection {
+            pattern: tags
+                .pattern
+                .as_deref()
+                .map(glob::Pattern::new)
+                .transpose()
+                .context("Invalid `tags.pattern` glob in configuration")?,
+            strip_prefix: tags.strip_prefix.clone(),
+            skip_prereleases: tags.skip_prereleases,
+        };
+
+        let path_scope = git::PathScope {
+            include: paths
+                .include
+                .iter()
+                .map(|pattern| glob::Pattern::new(pattern))
+                .collect::<std::result::Result<_, _>>()
+                .context("Invalid `paths.include` glob in configuration")?,
+            exclude: paths
+                .exclude
+                .iter()
+                .map(|pattern| glob::Pattern::new(pattern))
+                .collect::<std::result::Result<_, _>>()
+                .context("Invalid `paths.exclude` glob in configuration")?,
+        };
+
+        // `--base`/`--head` override the default "since the latest SemVer tag" range with an arbitrary one (e.g.
+        // `v1.2.0...release/2.0`), at the cost of there being no tag to infer the next version from.
+        let (last_tag, commits) = match (base, head) {
+            (Some(base), Some(head)) => {
+                let commits = git::get_commits_between(
+                    repository,
+                    git::GitReference::NamedRef(base.to_string()),
+                    git::GitReference::NamedRef(head.to_string()),
+                )
+                .context("Could not get commits between the given base and head references")?;
+
+                (None, commits)
+            }
+            _ => {
+                let (last_tag, commits) =
+                    git::get_commits_after_latest_tag(repository, &tag_selection, &path_scope)
+                        .context("Could not get commits after latest tag while creating new release")?;
+
+                (Some(last_tag), commits)
+            }
+        };
+
+        // No version was supplied on the command line, so infer the next one from Conventional Commit messages
+        // since the last tag rather than making the caller figure it out by hand.
+        let version = match version {
+            Some(version) => version.to_string(),
+            None => {
+                let last_tag = last_tag.ok_or_else(|| {
+                    report!(
+                        "Could not infer next version: there's no tag to infer a previous version from when using \
+                        `--base`/`--head`; pass the version explicitly"
+                    )
+                })?;
+
+                let draft = conventional::build_release_draft(&last_tag, &commits)
+                    .context("Could not infer next version from commits since the last tag; pass a version explicitly")?;
+
+                draft.next.to_string()
+            }
+        };
+
+        let parsed_version = semver::Version::parse(&version).context(format!(
             "Could not parse version '{}' according to SEMVER syntax",
             version
         ))?;
 
-        let (org, repo) = git::get_org_and_repo(repository)
-            .context("Could not get organization and repo from git")?;
-
-        let (_last_tag, commits) = git::get_commits_after_latest_tag(repository)
-            .context("Could not get commits after latest tag while creating new release")?;
+        let prerelease = prerelease_override.unwrap_or(!parsed_version.pre.is_empty());
 
-        let mut short_commits = HashMap::new();
-        let mut full_commits = HashMap::new();
+        let mut short_commits = IndexMap::new();
+        let mut full_commits = IndexMap::new();
 
         for commit in &commits {
             short_commits.insert(
@@ -450,115 +864,102 @@ impl Release {
             );
         }
 
+        let grouped_commits = conventional::group_commits(&commits, changelog_headings);
+
         let now = chrono::Local::now();
 
         // e.g., "January 26, 2025"
         let date = now.format("%B %d, %Y").to_string();
 
         Ok(Self {
-            organization: org,
-            repo,
-            version: version.to_string(),
+            organization: remote.owner.clone(),
+            repo: remote.name.clone(),
+            remote,
+            version,
+            prerelease,
+            draft,
             date,
             changelog: (PathBuf::new(), "".to_string()),
             full_commits,
             short_commits,
+            grouped_commits,
             assets,
         })
     }
 }
 
-async fn upload_asset(
-    client: &Octocrab,
-    owner: &str,
-    repo: &str,
-    release_id: u64,
-    asset: &Asset,
-) -> Result<(), Report> {
-    use tokio::fs;
-
-    let data = fs::read(&asset.path)
-        .await
-        .context(format!("could not read asset from {:#?}", asset.path))?;
-
-    let body = Bytes::from(data);
-
-    client
-        .repos(owner.to_owned(), repo.to_owned())
-        .releases()
-        .upload_asset(release_id, &asset.name, body)
-        // optionally .label("Some nice label")
-        .send()
-        .await
-        .context(format!(
-            "GitHub upload_asset call failed for {}",
-            asset.name
-        ))?;
-
-    Ok(())
-}
-
 impl Cli {
-    pub fn create_github_release(&self) -> Result<(), Report> {
-        debug!("Starting Github release");
+    pub fn create_release(&self) -> Result<(), Report> {
+        debug!("Starting release");
 
         let tag = format!("v{}", self.release.version);
 
-        let rt = tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()
-            .context("failed to create Tokio runtime")?;
-
-        debug!("Contacting Github to create release");
-
-        rt.block_on(async {
-            let client = Octocrab::builder()
-                .personal_token(self.conf.github.token.clone())
-                .build()
-                .context("failed to build GitHub client")?;
-
-            let created_release = client
-                .repos(&self.release.organization, &self.release.repo)
-                .releases()
-                .create(&tag)
-                .name(&tag)
-                .body(&self.release.changelog.1)
-                .send()
-                .await
-                .context("failure while attempting to create Github release")?;
-
-            success!(
-                "Created new Github release: {}",
-                created_release.html_url.as_str()
-            );
-
-            print!("Uploading release assets"; vec![polyfmt::Format::Spinner]);
-            for asset in &self.release.assets {
-                upload_asset(
-                    &client,
-                    &self.release.organization,
-                    &self.release.repo,
-                    created_release.id.0,
-                    asset,
-                )
-                .await
+        let forge = forge::select_forge(
+            &self.release.remote,
+            &self.conf.forges,
+            &self.conf.github.token,
+        )
+        .context("Could not determine which forge backend to use for this remote")?;
+
+        debug!("Contacting forge to create release");
+
+        let created_release = forge
+            .create_release(
+                &tag,
+                &tag,
+                &self.release.changelog.1,
+                self.release.prerelease,
+                self.release.draft,
+            )
+            .context("failure while attempting to create release")?;
+
+        success!("Created new release: {}", created_release.html_url);
+
+        print!("Uploading release assets"; vec![polyfmt::Format::Spinner]);
+        for asset in &self.release.assets {
+            let data = std::fs::read(&asset.path)
+                .context(format!("could not read asset from {:#?}", asset.path))?;
+
+            forge
+                .upload_asset(&created_release, &asset.name, bytes::Bytes::from(data))
                 .context(format!(
                     "Could not upload asset {} ({:#?}) to release",
                     asset.name, asset.path,
                 ))?;
 
-                success!("Successfully uploaded asset '{}'", asset.name);
-            }
+            success!("Successfully uploaded asset '{}'", asset.name);
+        }
+
+        // Only runs once the forge release and asset uploads above have succeeded, so a failed publish never
+        // orphans a tag with no corresponding release.
+        for publisher in publish::select_publishers(&self.conf.publish) {
+            print!("Publishing to {}", publisher.ecosystem(); vec![polyfmt::Format::Spinner]);
 
-            Ok::<_, Report>(())
-        })?;
+            publisher.publish().context(format!(
+                "Could not publish to {} registry",
+                publisher.ecosystem()
+            ))?;
+
+            success!(
+                "Published to {} ({})",
+                publisher.ecosystem(),
+                publisher.targets().join(", ")
+            );
+        }
 
         Ok(())
     }
 
     fn render_release_details(&self) -> Result<String, Report> {
+        let template = read_custom_template(
+            &self.conf.release_details.template,
+            &self.conf.release_details.template_path,
+        )
+        .context("Could not read custom release-details template")?
+        .unwrap_or_else(|| RELEASE_DETAILS_TEMPLATE.to_string());
+
         let mut tera = tera::Tera::default();
-        tera.add_raw_template("release_details", RELEASE_DETAILS_TEMPLATE)
+        tera.add_raw_template("release_details", &template)
             .context("Could not create text template")?;
 
         let colored_assets: HashMap<String, String> = self
@@ -580,12 +981,27 @@ impl Cli {
             &self.release.organization.blue().to_string(),
         );
         context.insert("repository", &self.release.repo.blue().to_string());
+        context.insert("grouped_commits", &self.release.grouped_commits);
         context.insert(
             "semver",
             &format!("v{}", self.release.version).blue().to_string(),
         );
         context.insert("date", &self.release.date.blue().to_string());
+        context.insert("prerelease", &self.release.prerelease);
+        context.insert("draft", &self.release.draft);
         context.insert("assets", &colored_assets);
+
+        let publishers: Vec<serde_json::Value> = publish::select_publishers(&self.conf.publish)
+            .iter()
+            .map(|publisher| {
+                serde_json::json!({
+                    "ecosystem": publisher.ecosystem().blue().to_string(),
+                    "targets": publisher.targets(),
+                })
+            })
+            .collect();
+        context.insert("publishers", &publishers);
+
         context.insert(
             "changelog_path",
             &self
@@ -0,0 +1,94 @@
+//! Post-release publishing to package-manager registries, run after the forge release and asset uploads complete
+//! so a failed publish never orphans a tag. Starts with a cargo/crates.io backend; npm/PyPI backends can implement
+//! the same `Publisher` trait later.
+
+use anyhow::{Context, Result, bail};
+
+/// A package-manager backend that can push the just-tagged version to its registry/registries.
+pub trait Publisher {
+    /// Short, human-facing name of the ecosystem this publisher handles, e.g. `"cargo"`. Used to label output.
+    fn ecosystem(&self) -> &'static str;
+
+    /// The registries this publisher will push to, for display in `render_release_details` before confirmation.
+    fn targets(&self) -> Vec<String>;
+
+    /// Publishes to every configured registry.
+    fn publish(&self) -> Result<()>;
+}
+
+/// Runs `cargo publish` once per configured registry, or just the default crates.io registry if none are
+/// configured. Registry tokens are resolved by cargo itself from `~/.cargo/config.toml` or the
+/// `CARGO_REGISTRIES_<NAME>_TOKEN` / `CARGO_REGISTRY_TOKEN` env vars; we never read or touch them directly.
+pub struct CargoPublisher {
+    registries: Vec<String>,
+}
+
+impl CargoPublisher {
+    pub fn new(registries: Vec<String>) -> Self {
+        Self { registries }
+    }
+}
+
+impl Publisher for CargoPublisher {
+    fn ecosystem(&self) -> &'static str {
+        "cargo"
+    }
+
+    fn targets(&self) -> Vec<String> {
+        if self.registries.is_empty() {
+            vec!["crates.io".to_string()]
+        } else {
+            self.registries.clone()
+        }
+    }
+
+    fn publish(&self) -> Result<()> {
+        if self.registries.is_empty() {
+            return run_cargo_publish(None);
+        }
+
+        for registry in &self.registries {
+            run_cargo_publish(Some(registry))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn run_cargo_publish(registry: Option<&str>) -> Result<()> {
+    let mut command = std::process::Command::new("cargo");
+    command.arg("publish");
+
+    if let Some(registry) = registry {
+        command.args(["--registry", registry]);
+    }
+
+    let status = command
+        .status()
+        .context("Could not run `cargo publish`; is cargo installed and on PATH?")?;
+
+    if !status.success() {
+        bail!(
+            "`cargo publish`{} exited with {status}",
+            registry
+                .map(|r| format!(" --registry {r}"))
+                .unwrap_or_default(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Resolves the set of publishers enabled by configuration. Returns an empty list when `publish.enable` is false;
+/// otherwise the cargo publisher is always included, targeting crates.io when no registries are configured.
+pub fn select_publishers(conf: &crate::cli::conf::Publish) -> Vec<Box<dyn Publisher>> {
+    let mut publishers: Vec<Box<dyn Publisher>> = Vec::new();
+
+    if !conf.enable {
+        return publishers;
+    }
+
+    publishers.push(Box::new(CargoPublisher::new(conf.cargo.registries.clone())));
+
+    publishers
+}
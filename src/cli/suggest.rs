@@ -0,0 +1,71 @@
+//! Small "did you mean" helper used to turn a mistyped config key, CLI value, etc. into a helpful suggestion,
+//! the same way Cargo hints at the closest valid command when you fat-finger one.
+
+/// Computes the Levenshtein edit distance between `a` and `b`: the minimum number of single-character insertions,
+/// deletions, or substitutions needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur_row = vec![0; b_chars.len() + 1];
+        cur_row[0] = i + 1;
+
+        for (j, &cb) in b_chars.iter().enumerate() {
+            let substitution_cost = if ca == cb { 0 } else { 1 };
+            cur_row[j + 1] = (cur_row[j] + 1) // insertion
+                .min(prev_row[j + 1] + 1) // deletion
+                .min(prev_row[j] + substitution_cost); // substitution
+        }
+
+        prev_row = cur_row;
+    }
+
+    prev_row[b_chars.len()]
+}
+
+/// Finds the closest match to `needle` among `candidates`, ignoring anything farther than
+/// `max(1, needle.len() / 3)` away so we don't suggest nonsense.
+pub(crate) fn closest_match<'a>(
+    needle: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = std::cmp::max(1, needle.len() / 3);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, edit_distance(needle, candidate)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Renders a `did you mean \`x\`?` hint for `needle`, or `None` if nothing in `candidates` is close enough.
+pub(crate) fn did_you_mean<'a>(
+    needle: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    closest_match(needle, candidates).map(|candidate| format!("did you mean `{candidate}`?"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_one_character_typo() {
+        let hint = did_you_mean("collor", ["color", "output_format", "debug"]);
+        assert_eq!(hint, Some("did you mean `color`?".to_string()));
+    }
+
+    #[test]
+    fn ignores_distant_candidates() {
+        let hint = did_you_mean("xyz", ["color", "output_format", "debug"]);
+        assert_eq!(hint, None);
+    }
+
+    #[test]
+    fn exact_match_has_zero_distance() {
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+}